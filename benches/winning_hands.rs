@@ -0,0 +1,62 @@
+//! Timings for `winning_hands` and the hand evaluation it is built on.
+//!
+//! Run with `cargo bench`. There is no benchmark framework: each case is run a fixed number
+//! of times and the mean time per call is printed.
+
+use poker::rng::SplitMix64;
+use poker::{winning_hands, Deck, Hand};
+use std::hint::black_box;
+use std::time::Instant;
+
+// `n` random hands, each dealt from its own shuffled deck
+fn random_hands(n: usize, seed: u64) -> Vec<String> {
+    let mut rng = SplitMix64::new(seed);
+    (0..n)
+        .map(|_| {
+            let mut deck = Deck::new();
+            deck.shuffle(&mut rng);
+            (0..5).map(|_| deck.deal().unwrap().to_string()).collect::<Vec<_>>().join(" ")
+        })
+        .collect()
+}
+
+fn bench(name: &str, iterations: u32, mut f: impl FnMut()) {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        f();
+    }
+    let per_call = start.elapsed() / iterations;
+    println!("{name:<32} {:>10.3} µs/call", per_call.as_secs_f64() * 1e6);
+}
+
+fn bench_winning_hands_n(n: usize) {
+    let hands = random_hands(n, n as u64);
+    let hands = hands.iter().map(String::as_str).collect::<Vec<_>>();
+    bench(&format!("winning_hands/{n}"), 10_000, || {
+        black_box(winning_hands(black_box(&hands)));
+    });
+}
+
+fn main() {
+    for n in [2, 5, 10, 100] {
+        bench_winning_hands_n(n);
+    }
+
+    let sources = random_hands(10_000, 0);
+    let hands = sources.iter().map(|s| Hand::from_str(s).unwrap()).collect::<Vec<_>>();
+    let mut i = 0;
+    bench("Hand::from_str", 10_000, || {
+        black_box(Hand::from_str(black_box(&sources[i % sources.len()])).unwrap());
+        i += 1;
+    });
+    bench("Hand::value_counts", 10_000, || {
+        black_box(black_box(&hands[i % hands.len()]).value_counts());
+        i += 1;
+    });
+    // is_straight only reads the rank, so time it with the evaluation that sets the rank
+    let cards = hands.iter().map(Hand::to_vec).collect::<Vec<_>>();
+    bench("Hand::from_cards + is_straight", 10_000, || {
+        black_box(Hand::from_cards(black_box(&cards[i % cards.len()])).unwrap().is_straight());
+        i += 1;
+    });
+}
@@ -0,0 +1,87 @@
+//! Chip accounting: stacks that bet, and pots shared out between winners.
+
+use std::error::Error;
+use std::fmt;
+
+/// Error returned when a stack is too short for a bet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InsufficientFunds {
+    /// The chips the bet needs.
+    pub needed: u64,
+    /// The chips in the stack.
+    pub available: u64,
+}
+
+impl fmt::Display for InsufficientFunds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Insufficient funds: {} chips needed, {} available", self.needed, self.available)
+    }
+}
+
+impl Error for InsufficientFunds {}
+
+/// The chips of one player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ChipStack(u64);
+
+impl ChipStack {
+    /// A stack of `chips`.
+    pub const fn new(chips: u64) -> ChipStack {
+        ChipStack(chips)
+    }
+
+    /// The number of chips in the stack.
+    pub const fn chips(&self) -> u64 {
+        self.0
+    }
+
+    /// Takes `amount` chips out of the stack, or none if it has fewer.
+    pub fn bet(&mut self, amount: u64) -> Result<(), InsufficientFunds> {
+        if amount > self.0 { return Err(InsufficientFunds { needed: amount, available: self.0 }) }
+        self.0 -= amount;
+        Ok(())
+    }
+
+    /// Adds won chips to the stack.
+    pub fn win(&mut self, amount: u64) {
+        self.0 += amount;
+    }
+}
+
+/// Chips bet by the players, and the players who can win them, by seat.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pot {
+    total: u64,
+    eligible_players: Vec<usize>,
+}
+
+impl Pot {
+    /// A pot of `total` chips that these players can win.
+    pub fn new(total: u64, eligible_players: Vec<usize>) -> Pot {
+        Pot { total, eligible_players }
+    }
+
+    /// The number of chips in the pot.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// The players who can win the pot.
+    pub fn eligible_players(&self) -> &[usize] {
+        &self.eligible_players
+    }
+
+    /// Splits the pot evenly between the eligible `winners`, in the order of
+    /// [`Pot::eligible_players`], the first of them also getting the odd chips.
+    ///
+    /// Winners who are not eligible get nothing; with no eligible winner, nobody does.
+    pub fn award_to_winners(&self, winners: &[usize]) -> Vec<(usize, u64)> {
+        let winners = self.eligible_players.iter().filter(|p| winners.contains(p)).collect::<Vec<_>>();
+        if winners.is_empty() { return vec![] }
+        let share = self.total / winners.len() as u64;
+        let odd_chips = self.total % winners.len() as u64;
+        winners.iter().enumerate()
+            .map(|(i, &&player)| (player, if i == 0 { share + odd_chips } else { share }))
+            .collect()
+    }
+}
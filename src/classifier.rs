@@ -0,0 +1,83 @@
+//! Ranking five cards by the rules of other games, through [`Hand::classify_with`](crate::Hand::classify_with).
+
+use std::cmp::Reverse;
+
+use crate::{quick_score, Card, CardValue, HandStrength, Rank};
+
+/// Ranks five cards by the rules of one game: a greater `Rank` is a better hand.
+pub trait HandClassifier {
+    /// What the cards are ranked by.
+    type Rank: PartialOrd;
+
+    /// The rank of these five cards.
+    fn classify(&self, cards: &[Card; 5]) -> Self::Rank;
+}
+
+/// The usual ranking of [`Hand`](crate::Hand).
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct StandardClassifier;
+
+/// Short Deck Hold'em, played without Twos to Fives: a Flush beats a Full House, and
+/// `A 6 7 8 9` is the lowest straight.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct ShortDeckClassifier;
+
+/// Ace-to-Five lowball: the lowest cards win, Aces are low, and straights and flushes don't count.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct LowballClassifier;
+
+impl HandClassifier for StandardClassifier {
+    type Rank = HandStrength;
+
+    fn classify(&self, cards: &[Card; 5]) -> HandStrength {
+        HandStrength(quick_score(cards))
+    }
+}
+
+impl HandClassifier for ShortDeckClassifier {
+    /// A score ordered like the standard one, with the short deck changes.
+    type Rank = u32;
+
+    fn classify(&self, cards: &[Card; 5]) -> u32 {
+        let mut score = quick_score(cards);
+        let mut values = cards.map(|c| c.value);
+        values.sort_unstable();
+        if values == [CardValue::Six, CardValue::Seven, CardValue::Eight, CardValue::Nine, CardValue::Ace] {
+            let flush = cards.iter().all(|c| c.suit == cards[0].suit);
+            let rank = if flush { Rank::StraightFlush } else { Rank::Straight };
+            // below the Ten-high straight, like the Five-high one in the standard game
+            score = rank.index() << 20 | 9 << 16 | 8 << 12 | 7 << 8 | 6 << 4 | 1;
+        }
+        let order = match Rank::from_index(score >> 20) {
+            Rank::Flush => Rank::FullHouse.index(),
+            Rank::FullHouse => Rank::Flush.index(),
+            rank => rank.index(),
+        };
+        order << 20 | score & 0xf_ffff
+    }
+}
+
+impl HandClassifier for LowballClassifier {
+    /// The lower the inner score, the better the low hand.
+    type Rank = Reverse<u32>;
+
+    fn classify(&self, cards: &[Card; 5]) -> Reverse<u32> {
+        let mut counts = [0u8; 14];
+        for c in cards {
+            counts[c.value.lowball_rank() as usize] += 1;
+        }
+        // values by group size then value, both descending
+        let mut groups = (1..14).rev().filter(|&v| counts[v] > 0).map(|v| (counts[v], v)).collect::<Vec<_>>();
+        groups.sort_by_key(|g| Reverse(g.0));
+        let rank = match (groups[0].0, groups.get(1).map_or(0, |g| g.0)) {
+            (4, _) => Rank::FourOfAKind,
+            (3, 2) => Rank::FullHouse,
+            (3, _) => Rank::ThreeOfAKind,
+            (2, 2) => Rank::TwoPair,
+            (2, _) => Rank::OnePair,
+            _ => Rank::HighCard,
+        };
+        Reverse(groups.iter().enumerate()
+            .fold(rank.index() << 20, |score, (i, &(_, v))| score | (v as u32) << (16 - 4 * i)))
+    }
+}
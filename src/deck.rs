@@ -0,0 +1,115 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::rng::Rng;
+use crate::{deck_card, Card, Hand};
+
+/// Error returned when cards cannot be dealt from a [`Deck`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeckError {
+    /// This card has already been dealt.
+    CardNotInDeck(Card),
+    /// Every card has already been dealt.
+    DeckExhausted,
+}
+
+impl fmt::Display for DeckError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DeckError::CardNotInDeck(c) => write!(f, "Card not in deck: {c}"),
+            DeckError::DeckExhausted => f.write_str("No cards left in the deck"),
+        }
+    }
+}
+
+impl Error for DeckError {}
+
+/// The cards not dealt yet, dealt from the end of the list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deck {
+    cards: Vec<Card>,
+}
+
+impl Deck {
+    /// A full, ordered deck of 52 cards.
+    pub fn new() -> Deck {
+        Deck { cards: (0..52).map(deck_card).collect() }
+    }
+
+    /// Shuffles the remaining cards (Fisher-Yates).
+    pub fn shuffle(&mut self, rng: &mut impl Rng) {
+        for i in (1..self.cards.len()).rev() {
+            self.cards.swap(i, rng.below(i + 1));
+        }
+    }
+
+    /// Deals the top card, `None` once the deck is empty.
+    pub fn deal(&mut self) -> Option<Card> {
+        self.cards.pop()
+    }
+
+    /// Like [`Deck::deal`], failing with [`DeckError::DeckExhausted`] once the deck is empty.
+    pub fn try_deal(&mut self) -> Result<Card, DeckError> {
+        self.deal().ok_or(DeckError::DeckExhausted)
+    }
+
+    /// Deals `N` random cards (a partial Fisher-Yates), panicking if fewer are left.
+    pub fn sample_without_replacement<const N: usize>(&mut self, rng: &mut impl Rng) -> [Card; N] {
+        assert!(N <= self.cards.len(), "not enough cards left in the deck");
+        let len = self.cards.len();
+        for i in (len - N..len).rev() {
+            self.cards.swap(i, rng.below(i + 1));
+        }
+        let mut sample = self.cards.drain(len - N..).rev();
+        std::array::from_fn(|_| sample.next().expect("N cards were drawn"))
+    }
+
+    /// A random card not dealt yet, left in the deck; `None` once the deck is empty.
+    pub fn peek_random_card(&self, rng: &mut impl Rng) -> Option<Card> {
+        if self.cards.is_empty() { return None }
+        Some(self.cards[rng.below(self.cards.len())])
+    }
+
+    /// Takes these cards out of the deck, wherever they are, or none of them if one is missing.
+    pub fn deal_specific(&mut self, cards: &[Card]) -> Result<(), DeckError> {
+        for (i, card) in cards.iter().enumerate() {
+            if !self.contains(card) || cards[..i].contains(card) {
+                return Err(DeckError::CardNotInDeck(*card))
+            }
+        }
+        self.cards.retain(|c| !cards.contains(c));
+        Ok(())
+    }
+
+    /// The cards of `hand` that have already been dealt from this deck.
+    pub fn missing_cards(&self, hand: &Hand) -> Vec<Card> {
+        hand.source_cards().into_iter().filter(|c| !self.contains(c)).collect()
+    }
+
+    /// Whether `card` has not been dealt yet.
+    pub fn contains(&self, card: &Card) -> bool {
+        self.cards.contains(card)
+    }
+
+    /// The cards not dealt yet, the top card last.
+    pub fn remaining(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// The number of cards not dealt yet.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Whether every card has been dealt.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+}
+
+impl Default for Deck {
+    /// A full, ordered deck, same as [`Deck::new`].
+    fn default() -> Deck {
+        Deck::new()
+    }
+}
@@ -0,0 +1,152 @@
+use crate::rng::SplitMix64;
+use crate::{best_hand, winners, Card, Deck};
+
+/// The betting rounds of a Texas Hold'em hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Street {
+    /// Before the hole cards are dealt.
+    Start,
+    /// Hole cards dealt, no community cards yet.
+    Preflop,
+    /// Three community cards.
+    Flop,
+    /// Four community cards.
+    Turn,
+    /// All five community cards.
+    River,
+}
+
+/// A seat at the table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Player {
+    /// The name shown for this player.
+    pub name: String,
+    /// The chips in front of the player.
+    pub stack: u64,
+    /// The two private cards, once dealt.
+    pub hole_cards: Option<[Card; 2]>,
+}
+
+impl Player {
+    /// A player with no cards yet.
+    pub fn new(name: &str, stack: u64) -> Player {
+        Player { name: name.to_string(), stack, hole_cards: None }
+    }
+}
+
+/// One hand of Texas Hold'em, from dealing the hole cards to the showdown.
+///
+/// The deal methods panic when called out of order.
+#[derive(Debug, Clone)]
+pub struct PokerGame {
+    players: Vec<Player>,
+    deck: Deck,
+    pot: u64,
+    community: Vec<Card>,
+    street: Street,
+}
+
+impl PokerGame {
+    /// The most players a deck can serve: two hole cards each, five community cards and
+    /// three burnt cards leave 2 × 22 + 8 = 52.
+    pub const MAX_PLAYERS: usize = 22;
+
+    /// A new hand for `players`, dealt from a deck shuffled with `seed`.
+    ///
+    /// Panics unless there are 1 to [`PokerGame::MAX_PLAYERS`] players.
+    pub fn new(players: Vec<Player>, seed: u64) -> PokerGame {
+        assert!(
+            (1..=PokerGame::MAX_PLAYERS).contains(&players.len()),
+            "a game needs 1 to {} players, not {}", PokerGame::MAX_PLAYERS, players.len(),
+        );
+        let mut deck = Deck::new();
+        deck.shuffle(&mut SplitMix64::new(seed));
+        PokerGame { players, deck, pot: 0, community: vec![], street: Street::Start }
+    }
+
+    /// The players, in seat order.
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+
+    /// The community cards dealt so far.
+    pub fn community(&self) -> &[Card] {
+        &self.community
+    }
+
+    /// The current street.
+    pub fn street(&self) -> Street {
+        self.street
+    }
+
+    /// The chips bet so far.
+    pub fn pot(&self) -> u64 {
+        self.pot
+    }
+
+    /// Moves `amount` chips from a player's stack to the pot, `false` if the stack is too short.
+    pub fn bet(&mut self, player: usize, amount: u64) -> bool {
+        let stack = &mut self.players[player].stack;
+        if *stack < amount { return false }
+        *stack -= amount;
+        self.pot += amount;
+        true
+    }
+
+    /// Deals two cards to every player.
+    pub fn deal_hole_cards(&mut self) {
+        self.advance(Street::Start, Street::Preflop);
+        for i in 0..self.players.len() {
+            let cards = [self.draw(), self.draw()];
+            self.players[i].hole_cards = Some(cards);
+        }
+    }
+
+    /// Burns a card and deals the three first community cards.
+    pub fn deal_flop(&mut self) {
+        self.advance(Street::Preflop, Street::Flop);
+        self.burn_and_deal(3)
+    }
+
+    /// Burns a card and deals the fourth community card.
+    pub fn deal_turn(&mut self) {
+        self.advance(Street::Flop, Street::Turn);
+        self.burn_and_deal(1)
+    }
+
+    /// Burns a card and deals the last community card.
+    pub fn deal_river(&mut self) {
+        self.advance(Street::Turn, Street::River);
+        self.burn_and_deal(1)
+    }
+
+    /// The indices of the players holding the best hand on the river, several on a split pot.
+    pub fn showdown(&self) -> Vec<usize> {
+        assert_eq!(self.street, Street::River, "showdown happens on the river");
+        let (seats, hands): (Vec<_>, Vec<_>) = self.players.iter().enumerate()
+            .filter_map(|(i, p)| p.hole_cards.map(|hole| {
+                let mut cards = hole.to_vec();
+                cards.extend_from_slice(&self.community);
+                (i, best_hand(&cards))
+            }))
+            .unzip();
+        winners(&seats, &hands)
+    }
+
+    fn advance(&mut self, from: Street, to: Street) {
+        assert_eq!(self.street, from, "cannot deal the {:?} now", to);
+        self.street = to;
+    }
+
+    fn draw(&mut self) -> Card {
+        self.deck.deal().expect("a deck has enough cards for MAX_PLAYERS, checked by new")
+    }
+
+    fn burn_and_deal(&mut self, n: usize) {
+        self.draw();
+        for _ in 0..n {
+            let card = self.draw();
+            self.community.push(card);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use std::marker::PhantomData;
+
+use crate::{quick_score_with, Card, HandParseError, HandStrength, Rank};
+
+/// Evaluates many hand strings one after the other, reusing the same buffers.
+///
+/// A pool is meant to stay on one thread: give each thread its own.
+#[derive(Debug)]
+pub struct HandPool {
+    cards: Vec<Card>,
+    counts: [u8; 15],
+    not_send: PhantomData<*const ()>,
+}
+
+impl Default for HandPool {
+    fn default() -> HandPool {
+        HandPool::new()
+    }
+}
+
+impl HandPool {
+    /// A pool with room for five cards.
+    pub fn new() -> HandPool {
+        HandPool { cards: Vec::with_capacity(5), counts: [0; 15], not_send: PhantomData }
+    }
+
+    /// The rank and strength of five space separated cards, like [`crate::Hand::from_str`]
+    /// but without building a hand. Fails with the same errors, checked in the same order.
+    pub fn evaluate(&mut self, hand_str: &str) -> Result<(Rank, HandStrength), HandParseError> {
+        let count = hand_str.split(' ').count();
+        if count != 5 { return Err(HandParseError::WrongCardCount(count)) }
+        self.cards.clear();
+        for s in hand_str.split(' ') {
+            self.cards.push(s.parse::<Card>()?);
+        }
+        for (i, card) in self.cards.iter().enumerate() {
+            if self.cards[..i].contains(card) { return Err(HandParseError::DuplicateCard(*card)) }
+        }
+        let strength = HandStrength(quick_score_with(&self.cards, &mut self.counts));
+        Ok((strength.to_rank(), strength))
+    }
+}
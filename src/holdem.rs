@@ -0,0 +1,260 @@
+//! Texas Hold'em analysis: hole cards against community cards.
+
+use std::collections::BTreeSet;
+
+use crate::probability::prob_of_rank_or_better;
+use crate::util::distinct_values;
+use crate::{
+    best_hand, best_hand_score, deck_card, for_each_combination, quick_score, straights, Card, CardSuit, CardValue,
+    HandParseError, HandPattern, Rank, Tuple, CARDSUITS, CARDVALUES,
+};
+
+/// Whether some two hole cards would make a straight with this board,
+/// that is whether three board values fit in the same straight.
+pub fn board_straight_possible(board: &[Card]) -> bool {
+    let values = board.iter().map(|c| c.value()).collect::<BTreeSet<_>>();
+    straights().any(|straight| straight.iter().filter(|v| values.contains(v)).count() >= 3)
+}
+
+/// The best rank any two hole cards can make with this board of three to five cards.
+///
+/// Panics if the board has fewer than three or more than five cards.
+pub fn nut_rank_given_board(board: &[Card]) -> Rank {
+    assert!((3..=5).contains(&board.len()), "a board has three to five cards");
+    let rest = (0..52).map(deck_card).filter(|c| !board.contains(c)).collect::<Vec<_>>();
+    let mut best = 0;
+    let mut cards = board.to_vec();
+    for_each_combination(&rest, 2, &mut |hole| {
+        cards.truncate(board.len());
+        cards.extend_from_slice(hole);
+        for_each_combination(&cards, 5, &mut |five| best = best.max(quick_score(five)));
+    });
+    Rank::from_index(best >> 20)
+}
+
+/// Whether the best hand of `hole` with this board is anything but a two pair resting on a
+/// board pair, which one more board card can counterfeit.
+///
+/// Panics if the board has fewer than three or more than five cards.
+pub fn is_counterfeit_safe(hole: &[Card; 2], board: &[Card]) -> bool {
+    assert!((3..=5).contains(&board.len()), "a board has three to five cards");
+    let mut cards = board.to_vec();
+    cards.extend_from_slice(hole);
+    let hand = best_hand(&cards);
+    if hand.rank != Rank::TwoPair { return true }
+    hand.freq.get(Tuple::Pair).iter().all(|&v| board.iter().filter(|c| c.value() == v).count() < 2)
+}
+
+/// The shape of two hole cards, as used in pre-flop hand analysis.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ConnectorType {
+    /// Two suited cards of adjacent values, like `9H 8H`.
+    SuitedConnector,
+    /// Two suited cards one value apart, like `9H 7H`.
+    SuitedOneGapper,
+    /// Two suited cards two values apart, like `9H 6H`.
+    SuitedTwoGapper,
+    /// Two unsuited cards of adjacent values, like `9H 8S`.
+    OffSuitConnector,
+    /// Two unsuited cards one value apart, like `9H 7S`.
+    OffSuitOneGapper,
+    /// Two cards of the same value.
+    Pair,
+    /// Two suited cards from Ten to Ace, like `KH QH`.
+    SuitedBroadway,
+    /// Two unsuited cards from Ten to Ace, like `KH QS`.
+    OffSuitBroadway,
+    /// Anything else.
+    Other,
+}
+
+/// Classifies two hole cards: pairs first, then broadway cards, then connectors and gappers.
+///
+/// An Ace connects both with a King and with a Two.
+pub fn classify_two_card_holding(cards: &[Card; 2]) -> ConnectorType {
+    let [a, b] = cards.map(|c| c.value());
+    let suited = cards[0].suit() == cards[1].suit();
+    if a == b { return ConnectorType::Pair }
+    if a.is_broadway() && b.is_broadway() {
+        return if suited { ConnectorType::SuitedBroadway } else { ConnectorType::OffSuitBroadway }
+    }
+    let gap = a.poker_rank().abs_diff(b.poker_rank()).min(a.lowball_rank().abs_diff(b.lowball_rank())) - 1;
+    match (suited, gap) {
+        (true, 0) => ConnectorType::SuitedConnector,
+        (true, 1) => ConnectorType::SuitedOneGapper,
+        (true, 2) => ConnectorType::SuitedTwoGapper,
+        (false, 0) => ConnectorType::OffSuitConnector,
+        (false, 1) => ConnectorType::OffSuitOneGapper,
+        _ => ConnectorType::Other,
+    }
+}
+
+// the values of a shorthand, higher first, and whether it takes suited and offsuit holdings
+fn parse_shorthand(s: &str) -> Result<(CardValue, CardValue, bool, bool), HandParseError> {
+    let invalid = || HandParseError::InvalidNotation(s.to_string());
+    let mut chars = s.chars();
+    let mut value = || chars.next().and_then(CardValue::from_char).ok_or_else(invalid);
+    let (first, second) = (value()?, value()?);
+    let (high, low) = if first >= second { (first, second) } else { (second, first) };
+    match (chars.next(), chars.next()) {
+        (None, _) => Ok((high, low, high != low, true)),
+        (Some('s'), None) if high != low => Ok((high, low, true, false)),
+        (Some('o'), None) if high != low => Ok((high, low, false, true)),
+        _ => Err(invalid()),
+    }
+}
+
+fn holdings(high: CardValue, low: CardValue, suited: bool, offsuit: bool) -> Vec<[Card; 2]> {
+    let mut holdings = Vec::new();
+    for (i, &high_suit) in CARDSUITS.iter().enumerate() {
+        for (j, &low_suit) in CARDSUITS.iter().enumerate() {
+            let keep = if high == low { i < j } else if i == j { suited } else { offsuit };
+            if keep { holdings.push([Card::new(high, high_suit), Card::new(low, low_suit)]) }
+        }
+    }
+    holdings
+}
+
+/// Every pair of hole cards matching a shorthand like `AKs` (suited), `AKo` (offsuit),
+/// `AK` (both) or `AA` (a pocket pair), the higher card first.
+///
+/// `AKs` gives 4 combinations, `AKo` 12, `AK` 16 and `AA` 6.
+pub fn from_shorthand(s: &str) -> Result<Vec<[Card; 2]>, HandParseError> {
+    let (high, low, suited, offsuit) = parse_shorthand(s)?;
+    Ok(holdings(high, low, suited, offsuit))
+}
+
+/// Every pair of hole cards in a comma separated range like `"TT+,AKs,AQo"`, without repeats.
+///
+/// Each item is a [`from_shorthand`] notation; a trailing `+` also takes the higher pairs
+/// (`TT+`), or the higher second cards below the first (`ATs+` is `ATs` to `AKs`).
+pub fn parse_range(range: &str) -> Result<Vec<[Card; 2]>, HandParseError> {
+    let mut range_holdings = Vec::new();
+    for item in range.split(',').map(str::trim) {
+        let (notation, plus) = item.strip_suffix('+').map_or((item, false), |notation| (notation, true));
+        let (high, low, suited, offsuit) =
+            parse_shorthand(notation).map_err(|_| HandParseError::InvalidNotation(item.to_string()))?;
+        let values = match (plus, high == low) {
+            (false, _) => vec![(high, low)],
+            (true, true) => CARDVALUES.into_iter().filter(|&v| v >= low).map(|v| (v, v)).collect(),
+            (true, false) => CARDVALUES.into_iter().filter(|&v| v >= low && v < high).map(|v| (high, v)).collect(),
+        };
+        for (high, low) in values {
+            for holding in holdings(high, low, suited, offsuit) {
+                if !range_holdings.contains(&holding) { range_holdings.push(holding) }
+            }
+        }
+    }
+    Ok(range_holdings)
+}
+
+/// What two hole cards make with the board, finer than a [`Rank`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum MadeHandCategory {
+    /// A straight flush with this top value.
+    StraightFlush {
+        /// The top value.
+        high: CardValue,
+        /// The suit of the flush.
+        suit: CardSuit,
+    },
+    /// Four cards of this value.
+    FourOfAKind(CardValue),
+    /// A full house.
+    FullHouse {
+        /// The value of the three cards.
+        trips: CardValue,
+        /// The value of the two cards.
+        pair: CardValue,
+    },
+    /// A flush holding the highest card of its suit not on the board.
+    NutFlush {
+        /// The suit of the flush.
+        suit: CardSuit,
+    },
+    /// Any other flush.
+    Flush {
+        /// The suit of the flush.
+        suit: CardSuit,
+    },
+    /// A straight with this top value.
+    Straight {
+        /// The top value.
+        high: CardValue,
+    },
+    /// Three of a kind from a pocket pair and one board card.
+    Set(CardValue),
+    /// Any other three of a kind, like one hole card with a board pair.
+    Trips(CardValue),
+    /// Two pair with the two highest board values, each paired by a hole card.
+    TopTwoPair {
+        /// The higher pair.
+        high: CardValue,
+        /// The lower pair.
+        low: CardValue,
+    },
+    /// Any other two pair.
+    TwoPair {
+        /// The higher pair.
+        high: CardValue,
+        /// The lower pair.
+        low: CardValue,
+    },
+    /// A pocket pair above every board card.
+    Overpair(CardValue),
+    /// A hole card paired with the highest board card.
+    TopPair(CardValue),
+    /// Any other pair.
+    Pair(CardValue),
+    /// No pair, with this highest value.
+    HighCard(CardValue),
+}
+
+/// Classifies the best hand of `hole` with a board of three to five cards.
+///
+/// Panics if the board has fewer than three or more than five cards.
+pub fn made_hand_category(hole: &[Card; 2], board: &[Card]) -> MadeHandCategory {
+    assert!((3..=5).contains(&board.len()), "a board has three to five cards");
+    let cards = [&hole[..], board].concat();
+    let pocket_pair = hole[0].value() == hole[1].value();
+    let board_values = distinct_values(board);
+    let in_hole = |value: CardValue| hole.iter().any(|c| c.value() == value);
+    match best_hand(&cards).match_pattern() {
+        HandPattern::StraightFlush { high, suit } => MadeHandCategory::StraightFlush { high, suit },
+        HandPattern::FourOfAKind { quad, .. } => MadeHandCategory::FourOfAKind(quad),
+        HandPattern::FullHouse { trips, pair } => MadeHandCategory::FullHouse { trips, pair },
+        HandPattern::Flush { suit, .. } => {
+            let nut = CARDVALUES.into_iter().rev()
+                .map(|v| Card::new(v, suit))
+                .find(|c| !board.contains(c))
+                .expect("a flush leaves cards of its suit off the board");
+            if hole.contains(&nut) { MadeHandCategory::NutFlush { suit } } else { MadeHandCategory::Flush { suit } }
+        }
+        HandPattern::Straight { high } => MadeHandCategory::Straight { high },
+        HandPattern::ThreeOfAKind { trips, .. } if pocket_pair && hole[0].value() == trips => MadeHandCategory::Set(trips),
+        HandPattern::ThreeOfAKind { trips, .. } => MadeHandCategory::Trips(trips),
+        HandPattern::TwoPair { high_pair, low_pair, .. }
+            if board_values.starts_with(&[high_pair, low_pair]) && in_hole(high_pair) && in_hole(low_pair) =>
+        {
+            MadeHandCategory::TopTwoPair { high: high_pair, low: low_pair }
+        }
+        HandPattern::TwoPair { high_pair, low_pair, .. } => MadeHandCategory::TwoPair { high: high_pair, low: low_pair },
+        HandPattern::OnePair { pair, .. } if pocket_pair && pair > board_values[0] => MadeHandCategory::Overpair(pair),
+        HandPattern::OnePair { pair, .. } if pair == board_values[0] && in_hole(pair) => MadeHandCategory::TopPair(pair),
+        HandPattern::OnePair { pair, .. } => MadeHandCategory::Pair(pair),
+        HandPattern::HighCard(values) => MadeHandCategory::HighCard(values[0]),
+    }
+}
+
+/// How much of the strength of `hole` with `board` comes from the board alone, from 0.0 to 1.0.
+///
+/// The strength of a rank is the share of the 2,598,960 five card hands ranked below it, so
+/// the result is the strength of the board's rank over the strength of the rank made with
+/// the hole cards. A board of fewer than five cards ranks by its groups of equal values only;
+/// an empty board, or a hand that is only a High Card, contributes 0.0.
+pub fn community_contribution(hole: &[Card; 2], board: &[Card]) -> f64 {
+    let strength = |cards: &[Card]| 1.0 - prob_of_rank_or_better(Rank::from_index(best_hand_score(cards) >> 20));
+    let total = strength(&[&hole[..], board].concat());
+    if total == 0.0 || board.is_empty() { return 0.0 }
+    (strength(board) / total).min(1.0)
+}
@@ -0,0 +1,42 @@
+//! Independent Chip Model: the prize money a tournament chip stack is worth.
+//!
+//! Uses the Malmuth-Harville model: a player finishes first with the fraction of all chips
+//! they hold, and each next place goes the same way among the players left.
+
+use std::collections::HashMap;
+
+/// The expected prize of every player, in the order of `stacks`, the first prize being
+/// `prizes[0]`.
+///
+/// Only the paid places are explored, so this stays fast while few places are paid.
+/// Panics with more than 64 players.
+pub fn icm_equity(stacks: &[u64], prizes: &[u64]) -> Vec<f64> {
+    assert!(stacks.len() <= 64, "at most 64 players");
+    let mut equity = vec![0.0; stacks.len()];
+    // the probability that the players of each set took the places paid so far
+    let mut placed = HashMap::from([(0u64, 1.0)]);
+    for &prize in prizes.iter().take(stacks.len()) {
+        let mut next = HashMap::new();
+        for (&set, &p) in &placed {
+            let left = (0..stacks.len()).filter(|i| set & 1 << i == 0).collect::<Vec<_>>();
+            let chips_left = left.iter().map(|&i| stacks[i]).sum::<u64>();
+            for &i in &left {
+                let share = match chips_left {
+                    0 => 1.0 / left.len() as f64,
+                    _ => stacks[i] as f64 / chips_left as f64,
+                };
+                equity[i] += p * share * prize as f64;
+                *next.entry(set | 1 << i).or_insert(0.0) += p * share;
+            }
+        }
+        placed = next;
+    }
+    equity
+}
+
+/// The expected prize of `stack` against the other players' `stacks`.
+pub fn icm_value(stack: u64, prize_pool: &[u64], stacks: &[u64]) -> f64 {
+    let mut all = vec![stack];
+    all.extend_from_slice(stacks);
+    icm_equity(&all, prize_pool)[0]
+}
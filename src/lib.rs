@@ -1,12 +1,116 @@
+//! Poker hand evaluation.
+//!
+//! Everything lives at the crate root:
+//!
+//! - [`Card`] is a [`CardValue`] and a [`CardSuit`], parsed from strings like `"10H"` or `"QS"`.
+//! - [`Hand`] is five cards parsed from a space separated string, together with its [`Rank`].
+//! - [`Tuple`] names the groups of equal values (pairs, triads, ...) found in a hand, collected in a [`FrequencyMap`].
+//! - [`HandStrength`] is a plain, totally ordered score of a hand.
+//! - [`best_hand_score`] scores the best five of some cards without building a [`Hand`].
+//! - [`winning_hands`] picks the best hand(s) out of a list of hand strings.
+//! - [`determine_winner`] does the same, telling an outright win from a split pot.
+//! - [`winning_hands_from_line`] reads the hands from one line, like `"4S 5H 4C 8D 4H | 10D JH QS KD AC"`.
+//! - [`winning_hands_from_iter`] and [`winning_hands_from_reader`] take them from an iterator or a reader.
+//!
+//! With the `serde` feature, a [`Hand`] serializes as `{ "cards": "AS KH QD JC 10S" }` and
+//! deserializes into a `Hand<'static>` through [`Hand::from_str`].
+
+#![deny(missing_docs)]
+
+use std::borrow::Cow;
 use std::cmp::{PartialOrd, Ordering};
 use std::collections::{BTreeSet, HashMap, BTreeMap};
+#[cfg(not(feature = "thiserror"))]
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::num::ParseIntError;
+use std::ops::Index;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+
+mod deck;
+mod game;
+mod hand_pool;
+pub mod chips;
+pub mod classifier;
+pub mod holdem;
+pub mod icm;
+pub mod pai_gow;
+pub mod pokerstars;
+pub mod probability;
+mod quick_eval;
+pub mod replay;
+pub mod rng;
+pub mod simulation;
+pub mod util;
+pub mod video_poker;
+
+pub use deck::{Deck, DeckError};
+pub use game::{Player, PokerGame, Street};
+pub use hand_pool::HandPool;
+pub use quick_eval::QuickEval;
 
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
-enum CardSuit {
-    Club, Diamond, Heart, Spade,
+/// The suit of a card, written `C`, `D`, `H` or `S`.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum CardSuit {
+    /// Clubs, `C`.
+    Club,
+    /// Diamonds, `D`.
+    Diamond,
+    /// Hearts, `H`.
+    Heart,
+    /// Spades, `S`.
+    Spade,
 }
 
 impl CardSuit {
+    /// 0 to 3 for Club, Diamond, Heart and Spade.
+    pub const fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Red for Diamonds and Hearts, black for Clubs and Spades.
+    pub const fn color(self) -> Color {
+        match self {
+            CardSuit::Diamond | CardSuit::Heart => Color::Red,
+            CardSuit::Club | CardSuit::Spade => Color::Black,
+        }
+    }
+
+    /// The suit of a Unicode suit symbol, black like `'♣'` or white like `'♧'`.
+    pub const fn from_unicode(c: char) -> Option<CardSuit> {
+        match c {
+            '♣' | '♧' => Some(CardSuit::Club),
+            '♦' | '♢' => Some(CardSuit::Diamond),
+            '♥' | '♡' => Some(CardSuit::Heart),
+            '♠' | '♤' => Some(CardSuit::Spade),
+            _ => None,
+        }
+    }
+
+    /// The black Unicode suit symbol, like `'♣'`.
+    pub const fn to_unicode(self) -> char {
+        match self {
+            CardSuit::Club => '♣',
+            CardSuit::Diamond => '♦',
+            CardSuit::Heart => '♥',
+            CardSuit::Spade => '♠',
+        }
+    }
+
+    /// The suit symbol followed by the emoji variation selector, shown in color where supported.
+    ///
+    /// This is two characters, so a string rather than a `char`.
+    pub const fn to_unicode_emoji(self) -> &'static str {
+        match self {
+            CardSuit::Club => "♣\u{fe0f}",
+            CardSuit::Diamond => "♦\u{fe0f}",
+            CardSuit::Heart => "♥\u{fe0f}",
+            CardSuit::Spade => "♠\u{fe0f}",
+        }
+    }
+
     fn from_str(s: &str) -> Result<CardSuit, ()> {
         match s {
             "C" => Ok(CardSuit::Club),
@@ -18,98 +122,780 @@ impl CardSuit {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-enum CardValue { // Ace may have a value of One
-    One, Two, Three, Four, Five, Six, Seven, Eight, Nine, Ten, Jack, Queen, King, Ace,
+/// The color of a suit.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Color {
+    /// Diamonds and Hearts.
+    Red,
+    /// Clubs and Spades.
+    Black,
+}
+
+/// The value of a card, written `2` to `10`, `J`, `Q`, `K` or `A`.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum CardValue {
+    /// 2
+    Two,
+    /// 3
+    Three,
+    /// 4
+    Four,
+    /// 5
+    Five,
+    /// 6
+    Six,
+    /// 7
+    Seven,
+    /// 8
+    Eight,
+    /// 9
+    Nine,
+    /// 10
+    Ten,
+    /// J
+    Jack,
+    /// Q
+    Queen,
+    /// K
+    King,
+    /// A
+    Ace,
 }
 
+const CARDVALUES: [CardValue; 13] = [
+    CardValue::Two, CardValue::Three, CardValue::Four, CardValue::Five, CardValue::Six,
+    CardValue::Seven, CardValue::Eight, CardValue::Nine, CardValue::Ten,
+    CardValue::Jack, CardValue::Queen, CardValue::King, CardValue::Ace,
+];
+
+const CARDSUITS: [CardSuit; 4] = [CardSuit::Club, CardSuit::Diamond, CardSuit::Heart, CardSuit::Spade];
 
 fn number_card(i: usize) -> Result<CardValue, ()> {
-    const CARDVALUES: [CardValue; 14] = [
-        CardValue::One, CardValue::Two, CardValue::Three, CardValue::Four, CardValue::Five,
-        CardValue::Six, CardValue::Seven, CardValue::Eight, CardValue::Nine, CardValue::Ten,
-        CardValue::Jack, CardValue::Queen, CardValue::King, CardValue::Ace,
-    ];
-    
     if (2..=10).contains(&i) {
-        Ok(CARDVALUES[i - 1])
+        Ok(CARDVALUES[i - 2])
     } else { 
         Err(())
     }
 }
 
-fn face_card(s: &str) -> Result<CardValue, ()> {
-    match s {
-        "J" => Ok(CardValue::Jack),
-        "Q" => Ok(CardValue::Queen),
-        "K" => Ok(CardValue::King),
-        "A" => Ok(CardValue::Ace),
-        _ => Err(()),
+impl CardValue {
+    /// 2 to 14 for Two to Ace, same as [`CardValue::poker_rank`].
+    pub const fn to_u8(self) -> u8 {
+        self.poker_rank()
     }
-}
 
-impl CardValue {
-    fn from_str(s: &str) -> Result<CardValue, ()> {
-        match s.parse::<usize>() {
-            Ok(i) => number_card(i),
-            Err(_) => face_card(s),
+    /// 2 to 14 for Two to Ace, the Ace ranking high.
+    pub const fn poker_rank(self) -> u8 {
+        self as u8 + 2
+    }
+
+    /// 1 for Ace, then 2 to 13 for Two to King, the Ace ranking low as in the `A 2 3 4 5` straight.
+    pub const fn lowball_rank(self) -> u8 {
+        match self {
+            CardValue::Ace => 1,
+            v => v.poker_rank(),
+        }
+    }
+
+    /// Parses a one character value: `2` to `9`, `T` for Ten, `J`, `Q`, `K` or `A`.
+    pub fn from_char(c: char) -> Option<CardValue> {
+        match c {
+            '2'..='9' => Some(CARDVALUES[c as usize - '2' as usize]),
+            'T' => Some(CardValue::Ten),
+            'J' => Some(CardValue::Jack),
+            'Q' => Some(CardValue::Queen),
+            'K' => Some(CardValue::King),
+            'A' => Some(CardValue::Ace),
+            _ => None,
+        }
+    }
+
+    /// The values of the Ace-high straight: Ten, Jack, Queen, King and Ace.
+    pub const fn broadway_values() -> [CardValue; 5] {
+        [CardValue::Ten, CardValue::Jack, CardValue::Queen, CardValue::King, CardValue::Ace]
+    }
+
+    /// The values of the Five-high straight, the Ace played low coming first.
+    pub const fn wheel_values() -> [CardValue; 5] {
+        [CardValue::Ace, CardValue::Two, CardValue::Three, CardValue::Four, CardValue::Five]
+    }
+
+    /// Whether this is Ten or higher.
+    pub const fn is_broadway(self) -> bool {
+        self as u8 >= CardValue::Ten as u8
+    }
+
+    fn from_str(s: &str) -> Result<CardValue, CardParseError> {
+        let invalid = || CardParseError::InvalidValue(s.to_string());
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (None, _) => Err(invalid()),
+            (Some(c), None) => CardValue::from_char(c).ok_or_else(invalid),
+            _ => match s.parse::<usize>() {
+                Ok(n) => number_card(n).map_err(|_| invalid()),
+                Err(e) => Err(CardParseError::NotANumber(s.to_string(), e)),
+            },
         }
     }
 }
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
-struct Card {
+/// A playing card. Cards order by value first, then by suit.
+///
+/// The suit only makes the order total, so that cards of equal value can share a
+/// `BTreeSet`; no poker comparison depends on it. Use [`Card::kicker_cmp`] to compare values.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct Card {
     value: CardValue,
     suit: CardSuit,
 }
 
 impl Card {
-    fn from_str(s: &str) -> Result<Card, ()> {
-        let suit = &s[s.len()-1..];
-        let value = &s[..s.len()-1];
-        let suit = CardSuit::from_str(suit);
-        let value = CardValue::from_str(value);
-        if suit.is_ok() && value.is_ok() {
-            Ok(Card {suit: suit.unwrap(), value: value.unwrap()})
-        } else {
-            Err(())
+    /// Builds a card from its value and suit.
+    pub const fn new(value: CardValue, suit: CardSuit) -> Card {
+        Card { value, suit }
+    }
+    /// Same as [`Card::new`], which is itself usable in constants:
+    /// `const ACE_OF_SPADES: Card = Card::new(CardValue::Ace, CardSuit::Spade);`
+    pub const fn new_const(value: CardValue, suit: CardSuit) -> Card {
+        Card::new(value, suit)
+    }
+    /// Same as [`Card::new`], handy next to the `From<(CardValue, CardSuit)>` conversion.
+    pub fn from_pair(value: CardValue, suit: CardSuit) -> Card {
+        Card::new(value, suit)
+    }
+    /// The value of the card.
+    pub fn value(&self) -> CardValue {
+        self.value
+    }
+    /// The suit of the card.
+    pub fn suit(&self) -> CardSuit {
+        self.suit
+    }
+    /// A copy of this card with another value.
+    pub fn with_value(&self, value: CardValue) -> Card {
+        Card { value, ..*self }
+    }
+    /// A copy of this card with another suit.
+    pub fn with_suit(&self, suit: CardSuit) -> Card {
+        Card { suit, ..*self }
+    }
+    /// Whether both cards have the same value.
+    pub fn same_value(&self, other: &Card) -> bool {
+        self.value == other.value
+    }
+    /// Whether both cards have the same suit.
+    pub fn same_suit(&self, other: &Card) -> bool {
+        self.suit == other.suit
+    }
+    /// Whether both cards are red or both are black.
+    pub fn same_color(&self, other: &Card) -> bool {
+        self.suit.color() == other.suit.color()
+    }
+    /// Whether this card is a better kicker than `other`: a higher value, whatever the suits.
+    pub fn beats_as_kicker(&self, other: &Card) -> bool {
+        self.value > other.value
+    }
+    /// Same as [`Card::beats_as_kicker`].
+    #[deprecated(note = "use beats_as_kicker")]
+    pub fn beats_for_kicker(&self, other: &Card) -> bool {
+        self.beats_as_kicker(other)
+    }
+    /// Compares the values only, unlike the [`Ord`] of cards which also compares suits.
+    pub fn kicker_cmp(&self, other: &Card) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+/// Error returned when a character is not a suit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidSuitChar(pub char);
+
+impl TryFrom<char> for CardSuit {
+    type Error = InvalidSuitChar;
+
+    /// Accepts `C`, `D`, `H` and `S` in either case, and the symbols of [`CardSuit::from_unicode`].
+    fn try_from(c: char) -> Result<CardSuit, InvalidSuitChar> {
+        match c {
+            'C' | 'c' => Ok(CardSuit::Club),
+            'D' | 'd' => Ok(CardSuit::Diamond),
+            'H' | 'h' => Ok(CardSuit::Heart),
+            'S' | 's' => Ok(CardSuit::Spade),
+            _ => CardSuit::from_unicode(c).ok_or(InvalidSuitChar(c)),
+        }
+    }
+}
+
+impl From<CardSuit> for char {
+    fn from(suit: CardSuit) -> char {
+        match suit {
+            CardSuit::Club => 'C',
+            CardSuit::Diamond => 'D',
+            CardSuit::Heart => 'H',
+            CardSuit::Spade => 'S',
+        }
+    }
+}
+
+impl From<CardSuit> for &'static str {
+    fn from(suit: CardSuit) -> &'static str {
+        match suit {
+            CardSuit::Club => "C",
+            CardSuit::Diamond => "D",
+            CardSuit::Heart => "H",
+            CardSuit::Spade => "S",
         }
     }
-    fn is_adjacent(&self, other: &Self) -> bool {
-        (self.value as i8 - other.value as i8).abs() == 1
+}
+
+/// Error returned when a number is not the value of a card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidCardValue(pub u8);
+
+impl TryFrom<u8> for CardValue {
+    type Error = InvalidCardValue;
+
+    /// Accepts 2 to 14, where 11 to 14 are Jack, Queen, King and Ace.
+    fn try_from(n: u8) -> Result<CardValue, InvalidCardValue> {
+        match n {
+            2..=14 => Ok(CARDVALUES[n as usize - 2]),
+            _ => Err(InvalidCardValue(n)),
+        }
+    }
+}
+
+impl From<CardValue> for u8 {
+    /// 2 to 14 for Two to Ace.
+    fn from(value: CardValue) -> u8 {
+        value.to_u8()
+    }
+}
+
+impl From<CardValue> for usize {
+    fn from(value: CardValue) -> usize {
+        u8::from(value) as usize
+    }
+}
+
+impl From<(CardValue, CardSuit)> for Card {
+    fn from((value, suit): (CardValue, CardSuit)) -> Card {
+        Card::new(value, suit)
+    }
+}
+
+impl From<Card> for (CardValue, CardSuit) {
+    fn from(card: Card) -> (CardValue, CardSuit) {
+        (card.value, card.suit)
     }
 }
 
-#[derive(PartialEq, Eq, PartialOrd, Ord)]
-enum Rank {
+impl fmt::Display for CardSuit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str((*self).into())
+    }
+}
+
+impl fmt::Display for CardValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CardValue::Ace => f.write_str("A"),
+            CardValue::Jack => f.write_str("J"),
+            CardValue::Queen => f.write_str("Q"),
+            CardValue::King => f.write_str("K"),
+            v => write!(f, "{}", u8::from(v)),
+        }
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.value, self.suit)
+    }
+}
+
+impl FromStr for Card {
+    type Err = CardParseError;
+
+    /// Parses a value followed by a suit, like `"10H"` or `"QS"`.
+    fn from_str(s: &str) -> Result<Card, CardParseError> {
+        match s.chars().count() {
+            0..=1 => return Err(CardParseError::TooShort(s.to_string())),
+            4.. => return Err(CardParseError::TooLong(s.to_string())),
+            _ => (),
+        }
+        let mut chars = s.chars();
+        let suit = chars.next_back().expect("at least two characters");
+        let value = chars.as_str();
+        let suit = CardSuit::from_str(&s[value.len()..]).map_err(|_| CardParseError::InvalidSuit(suit))?;
+        Ok(Card { value: CardValue::from_str(value)?, suit })
+    }
+}
+
+/// Error returned when a card string cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "thiserror", derive(thiserror::Error))]
+pub enum CardParseError {
+    /// The string has fewer than two characters.
+    #[cfg_attr(feature = "thiserror", error("Card too short: '{0}' — expected a value and a suit"))]
+    TooShort(String),
+    /// The string has more than three characters.
+    #[cfg_attr(feature = "thiserror", error("Card too long: '{0}' — expected a value and a suit"))]
+    TooLong(String),
+    /// The last character is not `C`, `D`, `H` or `S`.
+    #[cfg_attr(feature = "thiserror", error("Invalid card suit: '{0}' — expected C, D, H, or S"))]
+    InvalidSuit(char),
+    /// The value is not `2` to `10`, `J`, `Q`, `K` or `A`.
+    #[cfg_attr(feature = "thiserror", error("Invalid card value: '{0}' — expected 2 to 10, J, Q, K, or A"))]
+    InvalidValue(String),
+    /// The value is neither a letter nor a number.
+    #[cfg_attr(feature = "thiserror", error("Invalid card value: '{0}' — expected 2 to 10, J, Q, K, or A"))]
+    NotANumber(String, #[cfg_attr(feature = "thiserror", source)] ParseIntError),
+}
+
+#[cfg(not(feature = "thiserror"))]
+impl fmt::Display for CardParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CardParseError::TooShort(s) => write!(f, "Card too short: '{s}' — expected a value and a suit"),
+            CardParseError::TooLong(s) => write!(f, "Card too long: '{s}' — expected a value and a suit"),
+            CardParseError::InvalidSuit(c) => write!(f, "Invalid card suit: '{c}' — expected C, D, H, or S"),
+            CardParseError::InvalidValue(v) | CardParseError::NotANumber(v, _) => {
+                write!(f, "Invalid card value: '{v}' — expected 2 to 10, J, Q, K, or A")
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "thiserror"))]
+impl Error for CardParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CardParseError::NotANumber(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// The category of a hand, from the weakest to the strongest.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Rank {
+    /// Nothing better than the highest card.
     HighCard,
+    /// Two cards of the same value.
     OnePair,
+    /// Two different pairs.
     TwoPair,
-    ThreeOFAKind,
+    /// Three cards of the same value.
+    ThreeOfAKind,
+    /// Five consecutive values.
     Straight,
+    /// Five cards of the same suit.
     Flush,
+    /// Three of a kind and a pair.
     FullHouse,
+    /// Four cards of the same value.
     FourOfAKind,
+    /// A straight with all cards of the same suit.
     StraightFlush,
 }
 
-#[derive(PartialEq, Eq)]
-struct Hand<'a> {
+impl Rank {
+    /// Every rank, from the weakest to the strongest.
+    pub const ALL: [Rank; 9] = [
+        Rank::HighCard, Rank::OnePair, Rank::TwoPair, Rank::ThreeOfAKind, Rank::Straight,
+        Rank::Flush, Rank::FullHouse, Rank::FourOfAKind, Rank::StraightFlush,
+    ];
+
+    /// The usual name of the rank, like `"Straight Flush"`.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Rank::HighCard => "High Card",
+            Rank::OnePair => "One Pair",
+            Rank::TwoPair => "Two Pair",
+            Rank::ThreeOfAKind => "Three of a Kind",
+            Rank::Straight => "Straight",
+            Rank::Flush => "Flush",
+            Rank::FullHouse => "Full House",
+            Rank::FourOfAKind => "Four of a Kind",
+            Rank::StraightFlush => "Straight Flush",
+        }
+    }
+
+    /// An abbreviation of the name for table headers, like `"SF"` or `"2P"`.
+    pub const fn short_name(&self) -> &'static str {
+        match self {
+            Rank::HighCard => "HC",
+            Rank::OnePair => "P",
+            Rank::TwoPair => "2P",
+            Rank::ThreeOfAKind => "3K",
+            Rank::Straight => "St",
+            Rank::Flush => "F",
+            Rank::FullHouse => "FH",
+            Rank::FourOfAKind => "4K",
+            Rank::StraightFlush => "SF",
+        }
+    }
+
+    /// How many of the 2,598,960 five card hands of a standard deck have this rank.
+    ///
+    /// With 13 values, 4 suits and 10 straights (`A 2 3 4 5` to `10 J Q K A`):
+    ///
+    /// - straight flush: 10 × 4 = 40
+    /// - four of a kind: 13 × 48 = 624
+    /// - full house: 13 × C(4,3) × 12 × C(4,2) = 3,744
+    /// - flush: 4 × C(13,5) − 40 = 5,108
+    /// - straight: 10 × 4⁵ − 40 = 10,200
+    /// - three of a kind: 13 × C(4,3) × C(12,2) × 4² = 54,912
+    /// - two pair: C(13,2) × C(4,2)² × 11 × 4 = 123,552
+    /// - one pair: 13 × C(4,2) × C(12,3) × 4³ = 1,098,240
+    /// - high card: (C(13,5) − 10) × (4⁵ − 4) = 1,302,540
+    pub fn frequency_in_standard_deck(&self) -> u64 {
+        match self {
+            Rank::HighCard => 1_302_540,
+            Rank::OnePair => 1_098_240,
+            Rank::TwoPair => 123_552,
+            Rank::ThreeOfAKind => 54_912,
+            Rank::Straight => 10_200,
+            Rank::Flush => 5_108,
+            Rank::FullHouse => 3_744,
+            Rank::FourOfAKind => 624,
+            Rank::StraightFlush => 40,
+        }
+    }
+
+    /// Whether the rank is One Pair or better, anything but a High Card.
+    pub const fn is_made_hand(&self) -> bool {
+        !matches!(self, Rank::HighCard)
+    }
+
+    /// Whether the rank needs five cards of one suit: a Flush or a Straight Flush.
+    pub const fn requires_suited_cards(&self) -> bool {
+        matches!(self, Rank::Flush | Rank::StraightFlush)
+    }
+
+    /// Whether the rank needs five following values: a Straight or a Straight Flush.
+    pub const fn requires_connected_values(&self) -> bool {
+        matches!(self, Rank::Straight | Rank::StraightFlush)
+    }
+
+    /// Whether the rank can win at showdown, true for every rank of a standard deck.
+    pub const fn is_showdown_value(&self) -> bool {
+        true
+    }
+
+    fn index(&self) -> u32 {
+        match self {
+            Rank::HighCard => 0,
+            Rank::OnePair => 1,
+            Rank::TwoPair => 2,
+            Rank::ThreeOfAKind => 3,
+            Rank::Straight => 4,
+            Rank::Flush => 5,
+            Rank::FullHouse => 6,
+            Rank::FourOfAKind => 7,
+            Rank::StraightFlush => 8,
+        }
+    }
+
+    fn from_index(i: u32) -> Rank {
+        match i {
+            0 => Rank::HighCard,
+            1 => Rank::OnePair,
+            2 => Rank::TwoPair,
+            3 => Rank::ThreeOfAKind,
+            4 => Rank::Straight,
+            5 => Rank::Flush,
+            6 => Rank::FullHouse,
+            7 => Rank::FourOfAKind,
+            _ => Rank::StraightFlush,
+        }
+    }
+}
+
+/// A totally ordered score of a hand: two hands compare like their strengths.
+///
+/// Unlike [`Hand`] it borrows nothing, so it can be used as a map key or kept in a set.
+// rank in the top bits, then up to five tiebreaker values of four bits each
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct HandStrength(u32);
+
+impl HandStrength {
+    /// The rank of the hand this strength was computed from.
+    pub fn to_rank(&self) -> Rank {
+        Rank::from_index(self.0 >> 20)
+    }
+}
+
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl fmt::Display for HandStrength {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:#08x}", self.0)
+    }
+}
+
+/// One comparison made by [`Hand::partial_cmp_detailed`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ComparisonStep {
+    /// The ranks of both hands.
+    RankComparison(Rank, Rank),
+    /// A value of each hand, in tiebreak order.
+    TiebreakerValue(CardValue, CardValue),
+}
+
+/// Five cards and their evaluation, borrowing the string they were parsed from.
+///
+/// A `Hand<'static>` owns its source instead, for hands built from cards.
+/// Hands order by rank, then by the values that break ties for that rank.
+///
+/// Equality follows that order: two hands are equal when they split the pot, like
+/// `"2S 5H 7D 9C KH"` and `"2D 5C 7S 9H KD"`, even with other suits. Compare
+/// [`Hand::to_vec`] to tell the cards apart.
+///
+/// `Hand` has no [`Default`]: no five cards make a neutral hand, and any chosen sentinel
+/// would still rank and compare like a real hand. Use `Option<Hand>` for a missing hand.
+#[derive(Clone, Debug)]
+pub struct Hand<'a> {
     cards: BTreeSet<Card>,
-    src: &'a str,
+    src: Cow<'a, str>,
     rank: Rank,
-    freq: BTreeMap<Tuple, Vec<CardValue>>,
+    freq: FrequencyMap,
 }
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
-enum Tuple {
+/// A group of cards sharing the same value, from the largest to the smallest.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Tuple {
+    /// Four cards of one value.
     Quad,
+    /// Three cards of one value.
     Triad,
+    /// Two cards of one value.
     Pair,
+    /// A card whose value appears once.
     Single,
 }
 
-fn frequencies(values: Vec<CardValue>) -> Result<BTreeMap<Tuple, Vec<CardValue>>, ()> {
+impl Tuple {
+    /// The usual poker name of the group: `"pair"`, `"three of a kind"`, ...
+    pub fn to_poker_term(&self) -> &'static str {
+        match self {
+            Tuple::Quad => "four of a kind",
+            Tuple::Triad => "three of a kind",
+            Tuple::Pair => "pair",
+            Tuple::Single => "kicker",
+        }
+    }
+
+    /// The number of cards in the group.
+    pub fn to_count(&self) -> u8 {
+        match self {
+            Tuple::Quad => 4,
+            Tuple::Triad => 3,
+            Tuple::Pair => 2,
+            Tuple::Single => 1,
+        }
+    }
+}
+
+/// The values of a hand grouped by how many cards share them, each group highest first.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FrequencyMap(BTreeMap<Tuple, Vec<CardValue>>);
+
+impl FrequencyMap {
+    /// The values appearing in groups of this size, highest first.
+    pub fn get(&self, tuple: Tuple) -> &[CardValue] {
+        self.0.get(&tuple).map_or(&[][..], Vec::as_slice)
+    }
+
+    /// The value of the four of a kind, if any.
+    pub fn get_quads(&self) -> Option<CardValue> {
+        self.get(Tuple::Quad).first().copied()
+    }
+
+    /// The value of the three of a kind, if any.
+    pub fn get_trips(&self) -> Option<CardValue> {
+        self.get(Tuple::Triad).first().copied()
+    }
+
+    /// The values of the pairs, highest first.
+    pub fn get_pairs(&self) -> impl Iterator<Item = CardValue> + '_ {
+        self.get(Tuple::Pair).iter().copied()
+    }
+
+    /// The values appearing once, highest first.
+    pub fn get_singles(&self) -> impl Iterator<Item = CardValue> + '_ {
+        self.get(Tuple::Single).iter().copied()
+    }
+
+    /// Whether there are three cards of one value and two of another.
+    pub fn has_full_house(&self) -> bool {
+        self.get_trips().is_some() && self.get_pairs().next().is_some()
+    }
+
+    /// Whether there are exactly two pairs.
+    pub fn has_two_pair(&self) -> bool {
+        self.get(Tuple::Pair).len() == 2
+    }
+
+    /// The groups and their values, the largest group first.
+    pub fn iter(&self) -> impl Iterator<Item = (Tuple, &[CardValue])> {
+        self.0.iter().map(|(&t, v)| (t, v.as_slice()))
+    }
+
+    /// Every value, the largest group first and each group highest first.
+    pub fn values(&self) -> impl Iterator<Item = CardValue> + Clone + '_ {
+        self.0.values().flatten().copied()
+    }
+}
+
+/// The structure of a hand: its rank with the values that order it, highest first.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum HandPattern {
+    /// Five unpaired, unsuited, unconnected values.
+    HighCard([CardValue; 5]),
+    /// One pair and three kickers.
+    OnePair {
+        /// The value of the pair.
+        pair: CardValue,
+        /// The other values.
+        kickers: [CardValue; 3],
+    },
+    /// Two pairs and a kicker.
+    TwoPair {
+        /// The value of the higher pair.
+        high_pair: CardValue,
+        /// The value of the lower pair.
+        low_pair: CardValue,
+        /// The fifth value.
+        kicker: CardValue,
+    },
+    /// Three cards of a value and two kickers.
+    ThreeOfAKind {
+        /// The value of the three cards.
+        trips: CardValue,
+        /// The other values.
+        kickers: [CardValue; 2],
+    },
+    /// Five connected values.
+    Straight {
+        /// The top value, Five for `A 2 3 4 5`.
+        high: CardValue,
+    },
+    /// Five cards of a suit.
+    Flush {
+        /// The values of the cards.
+        values: [CardValue; 5],
+        /// The suit of the cards.
+        suit: CardSuit,
+    },
+    /// Three cards of a value and two of another.
+    FullHouse {
+        /// The value of the three cards.
+        trips: CardValue,
+        /// The value of the two cards.
+        pair: CardValue,
+    },
+    /// Four cards of a value and a kicker.
+    FourOfAKind {
+        /// The value of the four cards.
+        quad: CardValue,
+        /// The fifth value.
+        kicker: CardValue,
+    },
+    /// Five connected cards of a suit.
+    StraightFlush {
+        /// The top value, Five for `A 2 3 4 5`.
+        high: CardValue,
+        /// The suit of the cards.
+        suit: CardSuit,
+    },
+}
+
+/// The cards of a hand split for display, see [`Hand::decompose_for_display`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandDecomposition {
+    /// The rank of the hand.
+    pub rank: Rank,
+    /// The cards making the rank, larger groups first, then higher values first.
+    pub primary_cards: Vec<Card>,
+    /// The other cards, higher values first.
+    pub kicker_cards: Vec<Card>,
+    /// The hand in words, like `"Three Kings with Ace, Queen kickers"`.
+    pub description: String,
+}
+
+/// How [`Hand::to_notation_string`] joins the cards.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum NotationFormat {
+    /// No separator: `"AhKsQdJcTh"`.
+    Compact,
+    /// A space between cards: `"Ah Ks Qd Jc Th"`.
+    SpaceSeparated,
+}
+
+/// Error returned when a hand string cannot be parsed.
+///
+/// With the `thiserror` feature, this error and [`CardParseError`] derive
+/// [`std::error::Error`] and [`Display`](fmt::Display) instead of implementing them by hand;
+/// the messages are the same.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "thiserror", derive(thiserror::Error))]
+pub enum HandParseError {
+    /// The hand does not have exactly five cards.
+    #[cfg_attr(feature = "thiserror", error("Wrong card count: {0}"))]
+    WrongCardCount(usize),
+    /// A card is not a value followed by a suit.
+    #[cfg_attr(feature = "thiserror", error("Invalid card: {0}"))]
+    InvalidCard(#[cfg_attr(feature = "thiserror", from)] CardParseError),
+    /// This card appears twice in the hand.
+    #[cfg_attr(feature = "thiserror", error("Duplicate card: {0}"))]
+    DuplicateCard(Card),
+    /// This card was expected in the hand but is not there.
+    #[cfg_attr(feature = "thiserror", error("Card not in hand: {0}"))]
+    CardNotInHand(Card),
+    /// This card is not in the deck it should be dealt from.
+    #[cfg_attr(feature = "thiserror", error("Card not in deck: {0}"))]
+    CardNotInDeck(Card),
+    /// This is not a shorthand like `AKs`, `AKo`, `AK` or `AA`.
+    #[cfg_attr(feature = "thiserror", error("Invalid hand notation: '{0}'"))]
+    InvalidNotation(String),
+}
+
+#[cfg(not(feature = "thiserror"))]
+impl fmt::Display for HandParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HandParseError::WrongCardCount(n) => write!(f, "Wrong card count: {n}"),
+            HandParseError::InvalidCard(e) => write!(f, "Invalid card: {e}"),
+            HandParseError::DuplicateCard(c) => write!(f, "Duplicate card: {c}"),
+            HandParseError::CardNotInHand(c) => write!(f, "Card not in hand: {c}"),
+            HandParseError::CardNotInDeck(c) => write!(f, "Card not in deck: {c}"),
+            HandParseError::InvalidNotation(s) => write!(f, "Invalid hand notation: '{s}'"),
+        }
+    }
+}
+
+#[cfg(not(feature = "thiserror"))]
+impl Error for HandParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            HandParseError::InvalidCard(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(not(feature = "thiserror"))]
+impl From<CardParseError> for HandParseError {
+    fn from(e: CardParseError) -> HandParseError {
+        HandParseError::InvalidCard(e)
+    }
+}
+
+fn frequencies(values: Vec<CardValue>) -> FrequencyMap {
     let mut h1 = HashMap::<CardValue, u8>::new();
     let mut h2: HashMap<Tuple, BTreeSet<CardValue>> = HashMap::new();
     for v in values {
@@ -121,103 +907,1099 @@ fn frequencies(values: Vec<CardValue>) -> Result<BTreeMap<Tuple, Vec<CardValue>>
             2 => Tuple::Pair,
             3 => Tuple::Triad,
             4 => Tuple::Quad,
-            _ => { return Err(()) },
-        }).or_insert(BTreeSet::new()).insert(k);
+            _ => unreachable!("there are only four suits"),
+        }).or_default().insert(k);
     }
-    Ok(h2.into_iter()
+    FrequencyMap(h2.into_iter()
         .map(|(k, v)| (k, v.into_iter().rev().collect::<Vec<_>>()))
-        .collect::<BTreeMap<Tuple, Vec<CardValue>>>())
+        .collect())
+}
+
+
+// whether the values follow each other, an Ace counting high or low
+fn is_straight(cards: &BTreeSet<Card>) -> bool {
+    let follow = |ranks: &mut Vec<u8>| {
+        ranks.sort_unstable();
+        ranks.windows(2).all(|w| w[1] == w[0] + 1)
+    };
+    follow(&mut cards.iter().map(|c| c.value.poker_rank()).collect())
+        || follow(&mut cards.iter().map(|c| c.value.lowball_rank()).collect())
+}
+
+fn is_flush(cards: &BTreeSet<Card>) -> bool {
+    cards.iter().zip(cards.iter().skip(1)).all(|(c1, c2)| c1.suit == c2.suit)
 }
 
+fn parse_cards(src: &str) -> Result<Vec<Card>, HandParseError> {
+    src.split(' ')
+        .map(|s| s.parse::<Card>().map_err(HandParseError::from))
+        .collect()
+}
 
-fn ace_as_one(cards: &BTreeSet<Card>) -> BTreeSet<Card> {
-    cards.iter()
-        .map(|&c| if c.value == CardValue::Ace { Card { value: CardValue::One, ..c } } else { c }).collect::<BTreeSet<_>>()
+fn distinct_cards(cards: &[Card]) -> Result<BTreeSet<Card>, HandParseError> {
+    let mut set = BTreeSet::new();
+    for &c in cards {
+        if !set.insert(c) { return Err(HandParseError::DuplicateCard(c)) }
+    }
+    Ok(set)
 }
 
-fn is_straight(cards: &mut BTreeSet<Card>) -> bool {
-    cards.iter().zip(cards.iter().skip(1)).all(|(c1, c2)| c1.is_adjacent(c2)) || {
-        let mut alt_cards = ace_as_one(cards);
-        if alt_cards.iter().zip(alt_cards.iter().skip(1)).all(|(c1, c2)| c1.is_adjacent(c2)) {
-            cards.clear();
-            cards.append(&mut alt_cards);
-            true
-        } else {
-            false
+// the name of a value in words, like "King" or "Sixes"
+fn value_name(value: CardValue, plural: bool) -> &'static str {
+    let (one, many) = match value {
+        CardValue::Two => ("Two", "Twos"),
+        CardValue::Three => ("Three", "Threes"),
+        CardValue::Four => ("Four", "Fours"),
+        CardValue::Five => ("Five", "Fives"),
+        CardValue::Six => ("Six", "Sixes"),
+        CardValue::Seven => ("Seven", "Sevens"),
+        CardValue::Eight => ("Eight", "Eights"),
+        CardValue::Nine => ("Nine", "Nines"),
+        CardValue::Ten => ("Ten", "Tens"),
+        CardValue::Jack => ("Jack", "Jacks"),
+        CardValue::Queen => ("Queen", "Queens"),
+        CardValue::King => ("King", "Kings"),
+        CardValue::Ace => ("Ace", "Aces"),
+    };
+    if plural { many } else { one }
+}
+
+fn cards_to_string(cards: &[Card]) -> String {
+    cards.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(" ")
+}
+
+// calls f with every k-element subset of items, without collecting them
+fn for_each_combination<T: Copy>(items: &[T], k: usize, f: &mut impl FnMut(&[T])) {
+    fn visit<T: Copy>(items: &[T], k: usize, picked: &mut Vec<T>, f: &mut impl FnMut(&[T])) {
+        let needed = k - picked.len();
+        if needed == 0 { return f(picked) }
+        for i in 0..=items.len().saturating_sub(needed) {
+            if items.len() - i < needed { break }
+            picked.push(items[i]);
+            visit(&items[i + 1..], k, picked, f);
+            picked.pop();
         }
     }
+    visit(items, k, &mut Vec::with_capacity(k), f)
 }
 
-fn is_flush(cards: &BTreeSet<Card>) -> bool {
-    cards.iter().zip(cards.iter().skip(1)).all(|(c1, c2)| c1.suit == c2.suit)
+// same as Hand::score for up to five cards, without building a Hand;
+// fewer than five cards can only make groups of equal values
+fn quick_score(cards: &[Card]) -> u32 {
+    quick_score_with(cards, &mut [0; 15])
 }
 
-fn have_two_pair(freq: &BTreeMap<Tuple, Vec<CardValue>>) -> bool {
-    freq.contains_key(&Tuple::Pair) && freq.get(&Tuple::Pair).unwrap().len() == 2
+// same as quick_score, counting the values by poker rank in a caller provided array
+fn quick_score_with(cards: &[Card], counts: &mut [u8; 15]) -> u32 {
+    counts.fill(0);
+    for c in cards {
+        counts[c.value.poker_rank() as usize] += 1;
+    }
+    let flush = cards.len() == 5 && cards.iter().all(|c| c.suit == cards[0].suit);
+    let straight_high = if cards.len() == 5 {
+        (5..15).rev().find(|&high| (high - 4..=high).all(|r| counts[if r == 1 { 14 } else { r }] == 1))
+    } else {
+        None
+    };
+    // values by group size then value, both descending, like Hand::tiebreaker_values
+    let mut groups = [(0u8, 0usize); 5];
+    let mut n = 0;
+    for v in (2..15).rev().filter(|&v| counts[v] > 0) {
+        groups[n] = (counts[v], v);
+        n += 1;
+    }
+    groups[..n].sort_by_key(|g| std::cmp::Reverse(g.0));
+    let rank = match (groups[0].0, groups[1].0) {
+        _ if straight_high.is_some() && flush => Rank::StraightFlush,
+        (4, _) => Rank::FourOfAKind,
+        (3, 2) => Rank::FullHouse,
+        _ if flush => Rank::Flush,
+        _ if straight_high.is_some() => Rank::Straight,
+        (3, _) => Rank::ThreeOfAKind,
+        (2, 2) => Rank::TwoPair,
+        (2, _) => Rank::OnePair,
+        _ => Rank::HighCard,
+    };
+    if let Some(high) = straight_high {
+        for (i, g) in groups.iter_mut().enumerate() {
+            g.1 = high - i;
+        }
+    }
+    groups[..n].iter().enumerate()
+        .fold(rank.index() << 20, |score, (i, &(_, v))| score | (v as u32) << (16 - 4 * i))
+}
+
+// the best five card hand out of five or more distinct cards
+fn best_hand(cards: &[Card]) -> Hand<'static> {
+    let mut counts = [0; 15];
+    let mut best = None::<(u32, Vec<Card>)>;
+    for_each_combination(cards, 5, &mut |five| {
+        let score = quick_score_with(five, &mut counts);
+        if best.as_ref().is_none_or(|(top, _)| score >= *top) { best = Some((score, five.to_vec())) }
+    });
+    let (_, five) = best.expect("at least five cards");
+    Hand::from_cards(&five).expect("five distinct cards")
 }
 
-impl Hand<'_> {
-    fn from_str(src: &str) -> Result<Hand, ()> {
-        let cards = src.split(' ').collect::<Vec<_>>();
-        if cards.len() != 5 { return Err(()) }
-        let cards: Result<BTreeSet<Card>, ()> = cards.iter().map(|&s| Card::from_str(s)).collect();
-        if cards.is_err() { return Err(()) }
-        let mut cards = cards.unwrap();
+impl<'a> Hand<'a> {
+    /// Parses five space separated cards, like `"4S 5H 4C 8D 4H"`.
+    #[allow(clippy::should_implement_trait)] // FromStr cannot borrow from its input
+    pub fn from_str(src: &'a str) -> Result<Hand<'a>, HandParseError> {
+        let cards = src.split(' ').count();
+        if cards != 5 { return Err(HandParseError::WrongCardCount(cards)) }
+        Ok(Hand::evaluate(distinct_cards(&parse_cards(src)?)?, Cow::Borrowed(src)))
+    }
+
+    /// Like [`Hand::from_str`], also checking that every card is still in `deck`.
+    pub fn from_str_checked(src: &'a str, deck: &Deck) -> Result<Hand<'a>, HandParseError> {
+        let hand = Hand::from_str(src)?;
+        match hand.source_cards().into_iter().find(|c| !deck.contains(c)) {
+            Some(card) => Err(HandParseError::CardNotInDeck(card)),
+            None => Ok(hand),
+        }
+    }
+
+    /// Builds a hand from exactly five cards.
+    pub fn from_cards(cards: &[Card]) -> Result<Hand<'static>, HandParseError> {
+        if cards.len() != 5 { return Err(HandParseError::WrongCardCount(cards.len())) }
+        Ok(Hand::evaluate(distinct_cards(cards)?, Cow::Owned(cards_to_string(cards))))
+    }
+
+    /// Parses five to seven space separated cards and keeps the best five of them,
+    /// as when the hole cards and the board of a Hold'em hand are written together.
+    pub fn best_hand_from_str(src: &str) -> Result<Hand<'static>, HandParseError> {
+        let cards = parse_cards(src)?;
+        distinct_cards(&cards)?;
+        match cards.len() {
+            5..=7 => Ok(best_hand(&cards)),
+            n => Err(HandParseError::WrongCardCount(n)),
+        }
+    }
+
+    fn evaluate(cards: BTreeSet<Card>, src: Cow<'a, str>) -> Hand<'a> {
         let values = cards.iter().map(|c| c.value).collect::<Vec<_>>();
-        let freq = frequencies(values)?;
+        let freq = frequencies(values);
+        let straight = is_straight(&cards);
         let rank = {
-            if is_straight(&mut cards) && is_flush(&cards) { Rank::StraightFlush }
-            else if freq.contains_key(&Tuple::Quad) { Rank::FourOfAKind }
-            else if freq.contains_key(&Tuple::Triad) && freq.contains_key(&Tuple::Pair) { Rank::FullHouse }
+            if straight && is_flush(&cards) { Rank::StraightFlush }
+            else if freq.get_quads().is_some() { Rank::FourOfAKind }
+            else if freq.has_full_house() { Rank::FullHouse }
             else if is_flush(&cards) { Rank::Flush }
-            else if is_straight(&mut cards) { Rank::Straight }
-            else if freq.contains_key(&Tuple::Triad) { Rank::ThreeOFAKind }
-            else if have_two_pair(&freq) { Rank::TwoPair }
-            else if freq.contains_key(&Tuple::Pair) { Rank::OnePair }
+            else if straight { Rank::Straight }
+            else if freq.get_trips().is_some() { Rank::ThreeOfAKind }
+            else if freq.has_two_pair() { Rank::TwoPair }
+            else if freq.get_pairs().next().is_some() { Rank::OnePair }
             else { Rank::HighCard }
         };
-        Ok(Hand {cards, src, rank, freq})
+        Hand {cards, src, rank, freq}
+    }
+
+    /// A new hand with `old_card` swapped for `new_card`, as when redrawing in video poker.
+    pub fn replace_card(&self, old_card: Card, new_card: Card) -> Result<Hand<'static>, HandParseError> {
+        let mut cards = self.source_cards();
+        match cards.iter().position(|&c| c == old_card) {
+            Some(i) => cards[i] = new_card,
+            None => return Err(HandParseError::CardNotInHand(old_card)),
+        }
+        Hand::from_cards(&cards)
+    }
+
+    /// A new hand without the `discard` cards and with the `new_cards` added.
+    pub fn discard_and_draw(&self, discard: &[Card], new_cards: &[Card]) -> Result<Hand<'static>, HandParseError> {
+        let mut cards = self.source_cards();
+        for old_card in discard {
+            match cards.iter().position(|c| c == old_card) {
+                Some(i) => { cards.remove(i); },
+                None => return Err(HandParseError::CardNotInHand(*old_card)),
+            }
+        }
+        cards.extend_from_slice(new_cards);
+        Hand::from_cards(&cards)
+    }
+
+    // the cards lowest first
+    fn dealt_cards(&self) -> std::collections::btree_set::IntoIter<Card> {
+        self.cards.clone().into_iter()
+    }
+
+    // the cards in their source order
+    pub(crate) fn source_cards(&self) -> Vec<Card> {
+        parse_cards(&self.src).expect("a hand source is always valid")
+    }
+
+    /// The string this hand was parsed from, or its cards when it was built from cards.
+    pub fn source(&self) -> &str {
+        &self.src
+    }
+
+    /// The cards, highest first: the reverse of `hand[i]`, [`Hand::get`] and iterating the
+    /// hand, which all go lowest first.
+    pub fn to_vec(&self) -> Vec<Card> {
+        self.cards.iter().rev().copied().collect()
+    }
+
+    /// The card at `idx` counting from the lowest, like `hand[idx]`; `None` past the fifth card.
+    /// [`Hand::to_vec`] counts from the highest instead.
+    pub fn get(&self, idx: usize) -> Option<Card> {
+        self.cards.iter().nth(idx).copied()
+    }
+
+    /// `f` applied to the cards of both hands paired up in order, lowest first.
+    pub fn zip_with<T>(&self, other: &Hand, f: impl Fn(Card, Card) -> T) -> [T; 5] {
+        let mut pairs = self.cards.iter().zip(&other.cards);
+        std::array::from_fn(|_| {
+            let (&a, &b) = pairs.next().expect("a hand has five cards");
+            f(a, b)
+        })
+    }
+
+    /// The rank of the hand by the rules of `classifier`, as for other poker variants.
+    pub fn classify_with<C: classifier::HandClassifier>(&self, classifier: &C) -> C::Rank {
+        classifier.classify(&self.as_sorted_array())
+    }
+
+    /// The five cards, highest first like [`Hand::to_vec`], without allocating.
+    pub fn as_sorted_array(&self) -> [Card; 5] {
+        let mut cards = self.cards.iter().rev().copied();
+        [(); 5].map(|_| cards.next().expect("a hand has five cards"))
+    }
+
+    /// The rank of the hand together with the values that order it.
+    pub fn match_pattern(&self) -> HandPattern {
+        let group = |tuple| self.freq.get(tuple);
+        let singles = group(Tuple::Single);
+        let values = || {
+            let mut values = [CardValue::Two; 5];
+            for (v, c) in values.iter_mut().zip(self.dealt_cards().rev()) { *v = c.value }
+            values
+        };
+        match self.rank {
+            Rank::HighCard => HandPattern::HighCard(values()),
+            Rank::OnePair => HandPattern::OnePair {
+                pair: group(Tuple::Pair)[0],
+                kickers: [singles[0], singles[1], singles[2]],
+            },
+            Rank::TwoPair => HandPattern::TwoPair {
+                high_pair: group(Tuple::Pair)[0],
+                low_pair: group(Tuple::Pair)[1],
+                kicker: singles[0],
+            },
+            Rank::ThreeOfAKind => HandPattern::ThreeOfAKind {
+                trips: group(Tuple::Triad)[0],
+                kickers: [singles[0], singles[1]],
+            },
+            Rank::Straight => HandPattern::Straight { high: self.straight_high_card().expect("a straight") },
+            Rank::Flush => HandPattern::Flush { values: values(), suit: self.flush_suit().expect("a flush") },
+            Rank::FullHouse => HandPattern::FullHouse {
+                trips: group(Tuple::Triad)[0],
+                pair: group(Tuple::Pair)[0],
+            },
+            Rank::FourOfAKind => HandPattern::FourOfAKind { quad: group(Tuple::Quad)[0], kicker: singles[0] },
+            Rank::StraightFlush => HandPattern::StraightFlush {
+                high: self.straight_high_card().expect("a straight flush"),
+                suit: self.flush_suit().expect("a straight flush"),
+            },
+        }
+    }
+
+    /// The cards making the rank apart from the kickers, with a description of the hand.
+    pub fn decompose_for_display(&self) -> HandDecomposition {
+        let count = |c: &Card| self.count_of_value(c.value);
+        let mut cards = self.dealt_cards().rev().collect::<Vec<_>>();
+        if let Some(CardValue::Five) = self.straight_high_card() {
+            cards.rotate_left(1);
+        }
+        cards.sort_by_key(|c| std::cmp::Reverse(count(c)));
+        let primary = match self.rank {
+            Rank::HighCard => 1,
+            Rank::OnePair => 2,
+            Rank::ThreeOfAKind => 3,
+            Rank::TwoPair | Rank::FourOfAKind => 4,
+            _ => 5,
+        };
+        let kicker_cards = cards.split_off(primary);
+        let group = |tuple| self.freq.get(tuple);
+        let high = cards[0].value;
+        let description = match self.rank {
+            Rank::HighCard => format!("{} high", value_name(high, false)),
+            Rank::OnePair => format!("Pair of {}", value_name(high, true)),
+            Rank::TwoPair => format!("{} and {}", value_name(high, true), value_name(group(Tuple::Pair)[1], true)),
+            Rank::ThreeOfAKind => format!("Three {}", value_name(high, true)),
+            Rank::Straight => format!("Straight, {} high", value_name(high, false)),
+            Rank::Flush => format!("Flush, {} high", value_name(high, false)),
+            Rank::FullHouse => format!("{} full of {}", value_name(high, true), value_name(group(Tuple::Pair)[0], true)),
+            Rank::FourOfAKind => format!("Four {}", value_name(high, true)),
+            Rank::StraightFlush if high == CardValue::Ace => "Royal flush".to_string(),
+            Rank::StraightFlush => format!("Straight flush, {} high", value_name(high, false)),
+        };
+        let description = match kicker_cards.len() {
+            0 => description,
+            n => {
+                let kickers = kicker_cards.iter().map(|c| value_name(c.value, false)).collect::<Vec<_>>();
+                format!("{description} with {} {}", kickers.join(", "), if n == 1 { "kicker" } else { "kickers" })
+            }
+        };
+        HandDecomposition { rank: self.rank, primary_cards: cards, kicker_cards, description }
+    }
+
+    /// The cards in solver notation, highest first: `T` for Ten and lowercase suits.
+    pub fn to_notation_string(&self, format: NotationFormat) -> String {
+        let cards = self.dealt_cards().rev().map(|c| {
+            let suit = char::from(c.suit).to_ascii_lowercase();
+            match c.value {
+                CardValue::Ten => format!("T{suit}"),
+                v => format!("{v}{suit}"),
+            }
+        });
+        let separator = match format {
+            NotationFormat::Compact => "",
+            NotationFormat::SpaceSeparated => " ",
+        };
+        cards.collect::<Vec<_>>().join(separator)
+    }
+
+    /// The short name of the rank, a colon and the cards in compact notation, like
+    /// `"SF:AhKhQhJhTh"`; see [`Rank::short_name`] and [`Hand::to_notation_string`].
+    pub fn to_fen_like_notation(&self) -> String {
+        format!("{}:{}", self.rank.short_name(), self.to_notation_string(NotationFormat::Compact))
+    }
+
+    /// Parses [`Hand::to_fen_like_notation`], failing with [`HandParseError::InvalidNotation`]
+    /// when the rank does not match the cards.
+    pub fn from_fen_like(s: &str) -> Result<Hand<'static>, HandParseError> {
+        let invalid = || HandParseError::InvalidNotation(s.to_string());
+        let (rank, cards) = s.split_once(':').ok_or_else(invalid)?;
+        let rank = Rank::ALL.into_iter().find(|r| r.short_name() == rank).ok_or_else(invalid)?;
+        let hand = Hand::from_cards(&pokerstars::parse_compact_cards(cards)?)?;
+        if hand.rank != rank { return Err(invalid()) }
+        Ok(hand)
+    }
+
+    /// The hand in four bytes, big-endian: a 2-bit format version (0), then the deck index
+    /// (0 for the Two of Clubs to 51 for the Ace of Spades) of each card in 6 bits, highest first.
+    pub fn to_compressed_bytes(&self) -> [u8; 4] {
+        self.cards.iter().rev()
+            .fold(0u32, |bits, &c| bits << 6 | deck_index(c) as u32)
+            .to_be_bytes()
+    }
+
+    /// Reads [`Hand::to_compressed_bytes`], `None` for another version or cards that are not
+    /// five distinct ones, highest first.
+    pub fn from_compressed_bytes(bytes: [u8; 4]) -> Option<Hand<'static>> {
+        let bits = u32::from_be_bytes(bytes);
+        if bits >> 30 != 0 { return None }
+        let indices = [24, 18, 12, 6, 0].map(|shift| (bits >> shift & 0x3f) as usize);
+        if indices.windows(2).any(|w| w[0] <= w[1]) || indices[0] >= 52 { return None }
+        Hand::from_cards(&indices.map(deck_card)).ok()
+    }
+
+    /// The same hand, owning its source string.
+    pub fn into_owned(self) -> Hand<'static> {
+        Hand { src: Cow::Owned(self.src.into_owned()), ..self }
+    }
+
+    /// The values compared, in order, between hands of the same rank: the top value of a
+    /// straight, otherwise the largest group first and each group highest first, as in
+    /// `[pair, kicker, kicker, kicker]` for One Pair.
+    pub fn tiebreaker_values(&self) -> Vec<CardValue> {
+        match self.straight_high_card() {
+            Some(high) => vec![high],
+            None => self.freq.values().collect(),
+        }
+    }
+
+    /// A number that orders hands like [`Hand`] does: the rank, then the tiebreaker values.
+    pub fn score(&self) -> u32 {
+        let ranks = match self.straight_high_card() {
+            // every value of the straight, the Ace of the Five-high one counting 1
+            Some(high) => (0..5).map(|i| high.poker_rank() - i).collect::<Vec<_>>(),
+            None => self.freq.values().map(CardValue::poker_rank).collect(),
+        };
+        ranks.into_iter().enumerate()
+            .fold(self.rank.index() << 20, |score, (i, r)| score | (r as u32) << (16 - 4 * i))
+    }
+
+    /// The [`HandStrength`] of this hand.
+    pub fn strength(&self) -> HandStrength {
+        HandStrength(self.score())
+    }
+
+    /// Compares like [`Ord`], listing each comparison made up to the first that decides.
+    pub fn partial_cmp_detailed(&self, other: &Hand) -> (Ordering, Vec<ComparisonStep>) {
+        let mut steps = vec![ComparisonStep::RankComparison(self.rank, other.rank)];
+        let mut order = self.rank.cmp(&other.rank);
+        if order == Ordering::Equal {
+            for (a, b) in self.tiebreaker_values().into_iter().zip(other.tiebreaker_values()) {
+                steps.push(ComparisonStep::TiebreakerValue(a, b));
+                order = a.cmp(&b);
+                if order != Ordering::Equal { break }
+            }
+        }
+        (order, steps)
+    }
+
+    /// Whether this hand wins against `other`.
+    pub fn beats(&self, other: &Hand) -> bool {
+        self.cmp(other) == Ordering::Greater
+    }
+
+    /// Whether this hand splits the pot with `other`.
+    pub fn ties_with(&self, other: &Hand) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+
+    /// Whether this hand loses against `other`.
+    pub fn is_weaker_than(&self, other: &Hand) -> bool {
+        self.cmp(other) == Ordering::Less
+    }
+
+    /// Whether `other` beats this hand while sharing one of its values, either by holding
+    /// more cards of a shared value or, with the same paired cards, by a higher kicker.
+    pub fn is_dominated_by(&self, other: &Hand) -> bool {
+        if !other.beats(self) { return false }
+        let mine = self.value_counts();
+        let theirs = other.value_counts();
+        let shared = mine.keys().filter(|v| theirs.contains_key(v)).collect::<Vec<_>>();
+        if shared.is_empty() { return false }
+        let paired = |(t, _): &(Tuple, &[CardValue])| *t != Tuple::Single;
+        shared.iter().any(|v| theirs[v] >= 2 && theirs[v] > mine[v])
+            || (self.rank == other.rank && self.freq.iter().filter(paired).eq(other.freq.iter().filter(paired)))
+    }
+
+    /// Whether this hand dominates `other`, see [`Hand::is_dominated_by`].
+    pub fn dominates(&self, other: &Hand) -> bool {
+        other.is_dominated_by(self)
+    }
+
+    /// Whether both hands have the same rank, whatever their values.
+    pub fn rank_eq(&self, other: &Hand) -> bool {
+        self.rank == other.rank
+    }
+
+    /// Compares the ranks only, so that two full houses are equal.
+    pub fn rank_cmp(&self, other: &Hand) -> Ordering {
+        self.rank.cmp(&other.rank)
+    }
+
+    /// The cards of this hand that appear in some hand of `target_rank` made with four
+    /// cards left in `deck`: the cards an opponent cannot hold for those hands.
+    pub fn block_outs(&self, target_rank: Rank, deck: &Deck) -> Vec<Card> {
+        let own = self.source_cards();
+        let rest = deck.remaining().iter().filter(|c| !own.contains(c)).copied().collect::<Vec<_>>();
+        own.into_iter()
+            .filter(|&blocker| {
+                let mut blocked = 0;
+                for_each_combination(&rest, 4, &mut |four| {
+                    let cards = [blocker, four[0], four[1], four[2], four[3]];
+                    if quick_score(&cards) >> 20 == target_rank.index() { blocked += 1 }
+                });
+                blocked > 0
+            })
+            .collect()
+    }
+
+    /// The fraction of `others` this hand beats, ties not counted; 0.0 when there are none.
+    pub fn relative_strength_vs(&self, others: &[Hand]) -> f64 {
+        if others.is_empty() { return 0.0 }
+        others.iter().filter(|h| self.beats(h)).count() as f64 / others.len() as f64
+    }
+
+    /// The distinct suits of the cards.
+    pub fn suits_present(&self) -> BTreeSet<CardSuit> {
+        self.cards.iter().map(|c| c.suit).collect()
+    }
+
+    /// Whether all four suits appear among the five cards.
+    pub fn is_rainbow(&self) -> bool {
+        self.suits_present().len() == 4
+    }
+
+    /// Whether all cards share one suit.
+    pub fn is_monotone(&self) -> bool {
+        self.suits_present().len() == 1
+    }
+
+    /// Whether exactly two suits appear.
+    pub fn is_two_tone(&self) -> bool {
+        self.suits_present().len() == 2
+    }
+
+    /// How many cards each suit has, most first, as four digits: `"5000"` for a flush,
+    /// `"3200"` for three and two cards of two suits, `"2111"` when every suit appears.
+    pub fn suit_distribution_string(&self) -> String {
+        let mut counts = [0; 4];
+        for c in &self.cards {
+            counts[c.suit.to_u8() as usize] += 1;
+        }
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        counts.iter().map(|n| n.to_string()).collect()
+    }
+
+    /// The suit with the most cards and its count, the higher suit on a tie.
+    pub fn dominant_suit(&self) -> Option<(CardSuit, usize)> {
+        let mut counts = [0; 4];
+        for c in &self.cards {
+            counts[c.suit.to_u8() as usize] += 1;
+        }
+        CARDSUITS.into_iter().zip(counts).filter(|&(_, n)| n > 0).max_by_key(|&(suit, n)| (n, suit))
+    }
+
+    /// Whether some value appears exactly twice, as in a pair, two pair or full house.
+    pub fn has_pair_on_board(&self) -> bool {
+        self.freq.get_pairs().next().is_some()
+    }
+
+    /// How many of the 47 unseen cards would make a straight when swapped for one card of
+    /// the hand: 8 for an open-ended draw, 4 for a gutshot, 0 without a draw or with a straight.
+    pub fn number_of_outs_to_straight(&self) -> u8 {
+        if matches!(self.rank, Rank::Straight | Rank::StraightFlush) { return 0 }
+        let values = self.cards.iter().map(|c| c.value).collect::<BTreeSet<_>>();
+        let missing = straights()
+            .filter_map(|straight| {
+                let absent = straight.iter().filter(|v| !values.contains(v)).collect::<Vec<_>>();
+                if absent.len() == 1 { Some(*absent[0]) } else { None }
+            })
+            .collect::<BTreeSet<_>>();
+        missing.len() as u8 * 4
+    }
+
+    /// How many of the 47 unseen cards would make a flush when swapped for one card of
+    /// the hand: 9 with four cards of a suit, 0 otherwise.
+    pub fn number_of_outs_to_flush(&self) -> u8 {
+        let cards = self.to_vec();
+        let most = CARDSUITS.iter().map(|&suit| util::suit_count(suit, &cards)).max().unwrap_or(0);
+        if most == 4 { 9 } else { 0 }
+    }
+
+    /// Whether the hand holds four cards of a suit, the highest of them above every card of
+    /// that suit left in `remaining_deck`.
+    pub fn is_nut_flush_draw(&self, remaining_deck: &Deck) -> bool {
+        let Some((suit, 4)) = self.dominant_suit() else { return false };
+        let own = self.dealt_cards().filter(|c| c.suit == suit).collect::<Vec<_>>();
+        let top = own.iter().map(|c| c.value).max();
+        remaining_deck.remaining().iter()
+            .filter(|c| c.suit == suit && !own.contains(c))
+            .all(|c| Some(c.value) < top)
+    }
+
+    /// Whether one card of `remaining_deck` completes a straight, and the best such straight
+    /// is above any other straight the cards left could make with three values of the hand.
+    pub fn is_nut_straight_draw(&self, remaining_deck: &Deck) -> bool {
+        if matches!(self.rank, Rank::Straight | Rank::StraightFlush) { return false }
+        let own = self.source_cards();
+        let values = own.iter().map(|c| c.value).collect::<BTreeSet<_>>();
+        let available = |v: &CardValue| remaining_deck.remaining().iter().any(|c| c.value == *v && !own.contains(c));
+        let reachable = |missing: usize| {
+            straights().enumerate()
+                .filter(|(_, straight)| {
+                    let absent = straight.iter().filter(|v| !values.contains(v)).collect::<Vec<_>>();
+                    absent.len() <= missing && absent.into_iter().all(available)
+                })
+                .map(|(i, _)| i)
+                .last()
+        };
+        match reachable(1) {
+            Some(draw) => reachable(2) == Some(draw),
+            None => false,
+        }
+    }
+
+    /// The cards found in both hands, as blockers between two holdings.
+    pub fn common_cards(&self, other: &Hand) -> impl Iterator<Item = Card> {
+        let theirs = other.source_cards();
+        self.source_cards().into_iter().filter(move |c| theirs.contains(c))
+    }
+
+    /// Whether the two hands share a card, so they cannot be dealt together.
+    pub fn blocks(&self, other: &Hand) -> bool {
+        self.common_cards(other).next().is_some()
+    }
+
+    /// The values of the cards of each suit present, highest first.
+    pub fn partition_by_suit(&self) -> HashMap<CardSuit, Vec<CardValue>> {
+        let mut suits = HashMap::<CardSuit, Vec<CardValue>>::new();
+        for c in self.dealt_cards().rev() {
+            suits.entry(c.suit).or_default().push(c.value);
+        }
+        suits
+    }
+
+    /// The values of the cards of `suit`, highest first.
+    pub fn cards_of_suit(&self, suit: CardSuit) -> Vec<CardValue> {
+        self.dealt_cards().rev().filter(|c| c.suit == suit).map(|c| c.value).collect()
+    }
+
+    /// The suits of the cards of each value present, in suit order.
+    pub fn partition_by_value(&self) -> HashMap<CardValue, Vec<CardSuit>> {
+        let mut values = HashMap::<CardValue, Vec<CardSuit>>::new();
+        for c in self.dealt_cards() {
+            values.entry(c.value).or_default().push(c.suit);
+        }
+        values
+    }
+
+    /// How many cards of each value are in the hand.
+    pub fn value_counts(&self) -> HashMap<CardValue, u8> {
+        let mut counts = HashMap::new();
+        for c in self.dealt_cards() {
+            *counts.entry(c.value).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// The values of the hand grouped by how many cards share them.
+    pub fn frequencies(&self) -> &FrequencyMap {
+        &self.freq
+    }
+
+    /// Whether a card of this value is in the hand.
+    pub fn contains_value(&self, value: CardValue) -> bool {
+        self.count_of_value(value) > 0
+    }
+
+    /// How many cards of this value are in the hand, from 0 to 4.
+    pub fn count_of_value(&self, value: CardValue) -> u8 {
+        self.freq.iter()
+            .find(|(_, values)| values.contains(&value))
+            .map_or(0, |(tuple, _)| tuple.to_count())
+    }
+
+    /// The value of the highest card, an Ace even in a Five-high straight.
+    pub fn highest_value(&self) -> CardValue {
+        self.freq.values().max().expect("a hand has cards")
+    }
+
+    /// The value of the lowest card, an Ace even in a Five-high straight.
+    pub fn lowest_value(&self) -> CardValue {
+        self.freq.values().min().expect("a hand has cards")
+    }
+
+    /// The top value of a straight or straight flush, `None` for any other rank.
+    pub fn straight_high_card(&self) -> Option<CardValue> {
+        match self.rank {
+            Rank::Straight | Rank::StraightFlush => {
+                let values = self.freq.get(Tuple::Single);
+                Some(if values[..2] == [CardValue::Ace, CardValue::Five] { CardValue::Five } else { values[0] })
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether this is a straight or straight flush from Ten to Ace.
+    pub fn has_broadway_straight(&self) -> bool {
+        self.straight_high_card() == Some(CardValue::Ace)
+    }
+
+    /// Whether this is a straight or straight flush from Ace to Five.
+    pub fn is_wheel_straight(&self) -> bool {
+        self.straight_high_card() == Some(CardValue::Five)
+    }
+
+    /// How many values are missing between the lowest and the highest card for a straight,
+    /// 0 for a straight. `u8::MAX` for paired hands or when the cards span more than ten values.
+    ///
+    /// An Ace counts as high or low, whichever leaves fewer gaps.
+    pub fn gap_count(&self) -> u8 {
+        if self.freq.get(Tuple::Single).len() != 5 { return u8::MAX }
+        let values = self.dealt_cards().map(|c| c.value.to_u8()).collect::<Vec<_>>();
+        let mut span = values[4] - values[0];
+        if values[4] == CardValue::Ace.to_u8() {
+            span = span.min(values[3] - CardValue::Ace.lowball_rank());
+        }
+        if (4..=9).contains(&span) { span - 4 } else { u8::MAX }
+    }
+
+    /// How far apart the values are: the highest minus the lowest rank of the distinct values,
+    /// from 1 to 12, an Ace counting as 14 or 1, whichever is smaller. Lower is more connected:
+    /// 4 for a straight, more than 8 for a very spread hand.
+    pub fn connectivity_score(&self) -> u8 {
+        let spread = |rank: fn(CardValue) -> u8| {
+            let ranks = self.freq.values().map(rank);
+            ranks.clone().max().unwrap_or(0) - ranks.min().unwrap_or(0)
+        };
+        spread(CardValue::poker_rank).min(spread(CardValue::lowball_rank))
+    }
+
+    /// Whether the hand is nothing better than a high card.
+    pub fn is_high_card(&self) -> bool {
+        self.rank == Rank::HighCard
+    }
+
+    /// Whether the hand is exactly one pair.
+    pub fn is_one_pair(&self) -> bool {
+        self.rank == Rank::OnePair
+    }
+
+    /// Whether the hand is two pair.
+    pub fn is_two_pair(&self) -> bool {
+        self.rank == Rank::TwoPair
+    }
+
+    /// Whether the hand is three of a kind.
+    pub fn is_three_of_a_kind(&self) -> bool {
+        self.rank == Rank::ThreeOfAKind
+    }
+
+    /// Whether the hand is a straight, not a straight flush.
+    pub fn is_straight(&self) -> bool {
+        self.rank == Rank::Straight
+    }
+
+    /// Whether the hand is a flush, not a straight flush.
+    pub fn is_flush(&self) -> bool {
+        self.rank == Rank::Flush
+    }
+
+    /// Whether the hand is a full house.
+    pub fn is_full_house(&self) -> bool {
+        self.rank == Rank::FullHouse
+    }
+
+    /// Whether the hand is four of a kind.
+    pub fn is_four_of_a_kind(&self) -> bool {
+        self.rank == Rank::FourOfAKind
+    }
+
+    /// Whether the hand is a straight flush.
+    pub fn is_straight_flush(&self) -> bool {
+        self.rank == Rank::StraightFlush
+    }
+
+    /// Same as [`Hand::is_two_pair`].
+    pub fn is_double_paired(&self) -> bool {
+        self.is_two_pair()
+    }
+
+    /// Whether this is a plain straight from Ace to Five; see [`Hand::is_wheel_straight`]
+    /// to include the straight flush.
+    pub fn is_ace_low_straight(&self) -> bool {
+        self.rank == Rank::Straight && self.is_wheel_straight()
+    }
+
+    /// Whether this is a plain straight from Ten to Ace; see [`Hand::has_broadway_straight`]
+    /// to include the straight flush.
+    pub fn is_ace_high_straight(&self) -> bool {
+        self.rank == Rank::Straight && self.has_broadway_straight()
+    }
+
+    /// The suit of a flush or straight flush, `None` for any other rank.
+    pub fn flush_suit(&self) -> Option<CardSuit> {
+        match self.rank {
+            Rank::Flush | Rank::StraightFlush => self.cards.iter().next().map(|c| c.suit),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<String> for Hand<'static> {
+    type Error = HandParseError;
+
+    /// Same as [`Hand::from_str`], keeping `src` as the source of the hand.
+    fn try_from(src: String) -> Result<Hand<'static>, HandParseError> {
+        let Hand { cards, rank, freq, .. } = Hand::from_str(&src)?;
+        Ok(Hand { cards, src: Cow::Owned(src), rank, freq })
+    }
+}
+
+// the serde form of a hand: `{ "cards": "AS KH QD JC 10S" }`
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Hand")]
+struct SerdeHand {
+    cards: String,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Hand<'_> {
+    /// Writes the source string of the hand as its `cards` field.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerdeHand { cards: self.src.to_string() }.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Hand<'static> {
+    /// Reads the `cards` field with [`Hand::try_from`], failing on an invalid hand.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Hand<'static>, D::Error> {
+        let SerdeHand { cards } = SerdeHand::deserialize(deserializer)?;
+        Hand::try_from(cards).map_err(serde::de::Error::custom)
+    }
+}
+
+impl FromIterator<Card> for Result<Hand<'static>, HandParseError> {
+    /// Same as [`Hand::from_cards`].
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> Self {
+        Hand::from_cards(&iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl FromIterator<Card> for Hand<'static> {
+    /// Same as [`Hand::from_cards`], panicking unless given five distinct cards.
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> Self {
+        iter.into_iter().collect::<Result<Hand, _>>().expect("five distinct cards")
+    }
+}
+
+impl IntoIterator for Hand<'_> {
+    type Item = Card;
+    type IntoIter = std::collections::btree_set::IntoIter<Card>;
+
+    /// The cards, lowest first.
+    fn into_iter(self) -> Self::IntoIter {
+        self.cards.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Hand<'_> {
+    type Item = &'a Card;
+    type IntoIter = std::collections::btree_set::Iter<'a, Card>;
+
+    /// The cards, lowest first.
+    fn into_iter(self) -> Self::IntoIter {
+        self.cards.iter()
     }
 }
 
+impl Index<usize> for Hand<'_> {
+    type Output = Card;
+
+    /// The card at `idx` counting from the lowest, unlike [`Hand::to_vec`]; panics past the
+    /// fifth card.
+    ///
+    /// There is no `Index<Range<usize>>`: the cards are kept in a `BTreeSet`, which has no
+    /// slice to hand out. Slice [`Hand::as_sorted_array`] instead.
+    fn index(&self, idx: usize) -> &Card {
+        self.cards.iter().nth(idx).unwrap_or_else(|| panic!("card index {idx} out of range for a hand"))
+    }
+}
+
+// equal exactly when neither hand beats the other, whatever the suits or the source
+impl<'a> PartialEq for Hand<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for Hand<'a> {}
+
 impl<'a> PartialOrd for Hand<'a> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        if self.rank != other.rank {
-           self.rank.partial_cmp(&other.rank)
-        } else {
-            match self.rank {
-                Rank::Straight | Rank::StraightFlush => self.cards.first().unwrap().partial_cmp(other.cards.first().unwrap()),
-                Rank::HighCard | Rank::Flush => {
-                    let v1 = self.cards.iter().rev().map(|c| c.value).collect::<Vec<_>>();
-                    let v2 = other.cards.iter().rev().map(|c| c.value).collect::<Vec<_>>();
-                    v1.partial_cmp(&v2)
-                },
-                _ => {
-                    let v1 = &self.freq.values().collect::<Vec<_>>();
-                    let v2 = &other.freq.values().collect::<Vec<_>>();
-                    v1.partial_cmp(v2)
-                }
-            }   
-        }
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
 impl<'a> Ord for Hand<'a> {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+        self.rank.cmp(&other.rank)
+            .then_with(|| util::cmp_kicker_chain(&self.tiebreaker_values(), &other.tiebreaker_values()))
+    }
+}
+
+// the values of the ten straights, from A 2 3 4 5 to 10 J Q K A, each lowest value first
+fn straights() -> impl Iterator<Item = [CardValue; 5]> {
+    std::iter::once(CardValue::wheel_values()).chain((4..13).map(|high| {
+        let mut values = [CardValue::Ace; 5];
+        values.copy_from_slice(&CARDVALUES[high - 4..=high]);
+        values
+    }))
+}
+
+// the 52 cards of a deck, Two of Clubs first and Ace of Spades last
+const DECK: [Card; 52] = {
+    let mut cards = [Card::new(CardValue::Two, CardSuit::Club); 52];
+    let mut i = 0;
+    while i < 52 {
+        cards[i] = Card::new(CARDVALUES[i / 4], CARDSUITS[i % 4]);
+        i += 1;
+    }
+    cards
+};
+
+fn deck_card(i: usize) -> Card {
+    DECK[i]
+}
+
+// the position of a card in DECK
+fn deck_index(card: Card) -> usize {
+    (card.value.poker_rank() as usize - 2) * 4 + card.suit.to_u8() as usize
+}
+
+/// Iterator over every five card hand of a standard deck, see [`all_hands`].
+#[derive(Clone, Debug)]
+pub struct AllHandsIter {
+    indices: [usize; 5],
+    done: bool,
+}
+
+impl Iterator for AllHandsIter {
+    type Item = [Card; 5];
+
+    fn next(&mut self) -> Option<[Card; 5]> {
+        if self.done { return None }
+        let hand = self.indices.map(deck_card);
+        match (0..5).rev().find(|&i| self.indices[i] < 47 + i) {
+            Some(i) => {
+                self.indices[i] += 1;
+                for j in i + 1..5 {
+                    self.indices[j] = self.indices[j - 1] + 1;
+                }
+            },
+            None => self.done = true,
+        }
+        Some(hand)
+    }
+}
+
+/// The [`Hand::score`] of the best five of `cards`, without building a [`Hand`] or allocating.
+///
+/// Fewer than five cards are scored by their groups of equal values only. The cards are
+/// expected to be distinct; panics with 64 cards or more.
+#[inline]
+pub fn best_hand_score(cards: &[Card]) -> u32 {
+    let mut counts = [0; 15];
+    if cards.len() <= 5 { return quick_score_with(cards, &mut counts) }
+    assert!(cards.len() < 64, "too many cards");
+    let mut five = [cards[0]; 5];
+    let mut best = 0;
+    // every set of five bits below 1 << cards.len(), in increasing order
+    let mut mask: u64 = 0b11111;
+    while mask < 1 << cards.len() {
+        let mut bits = mask;
+        for card in &mut five {
+            *card = cards[bits.trailing_zeros() as usize];
+            bits &= bits - 1;
+        }
+        best = best.max(quick_score_with(&five, &mut counts));
+        let low = mask & mask.wrapping_neg();
+        let carry = mask + low;
+        mask = (((carry ^ mask) >> 2) / low) | carry;
     }
+    best
+}
+
+/// All the C(52, 5) = 2,598,960 five card hands of a standard deck, in lexicographic order.
+pub fn all_hands() -> AllHandsIter {
+    AllHandsIter { indices: [0, 1, 2, 3, 4], done: false }
+}
+
+/// Every five card hand with its rank, the weakest rank first and each rank in the order of
+/// [`all_hands`]. Lazy: the deck is walked once per rank, keeping nothing.
+pub fn all_hands_by_rank() -> impl Iterator<Item = (Rank, Hand<'static>)> {
+    Rank::ALL.into_iter().flat_map(|rank| {
+        all_hands()
+            .filter(move |cards| quick_score(cards) >> 20 == rank.index())
+            .map(move |cards| (rank, Hand::from_cards(&cards).expect("five distinct cards")))
+    })
 }
 
+/// Picks the best hand(s) from a list of hand strings, in their input order.
+///
+/// Panics if one of the strings is not a valid hand.
 pub fn winning_hands<'a>(hands: &[&'a str]) -> Vec<&'a str> {
-    let hands: Result<Vec<_>, ()> = hands.iter().map(|&h| Hand::from_str(h)).collect();
-    if hands.is_err() { panic!("Error, check input string") }
-    let mut hands = hands.unwrap();
-    if hands.len() > 1 {
-        hands.sort();
-        hands.reverse();
-        let hand = &hands[0];
-        hands.iter().filter(|&h| h.cmp(hand) == Ordering::Equal).map(|h| h.src).collect()
-    } else {
-        hands.iter().map(|h| h.src).collect()
+    winning_hands_linear(hands)
+}
+
+fn winning_hands_linear<'a>(hands: &[&'a str]) -> Vec<&'a str> {
+    let parsed: Result<Vec<_>, _> = hands.iter().map(|&h| Hand::from_str(h)).collect();
+    if parsed.is_err() { panic!("Error, check input string") }
+    winners(hands, &parsed.unwrap())
+}
+
+// the items whose hand ties with the best of `hands`, in their order; one hand per item
+fn winners<T: Clone>(items: &[T], hands: &[Hand]) -> Vec<T> {
+    match hands.iter().max() {
+        Some(best) => items.iter().zip(hands)
+            .filter(|&(_, h)| h.ties_with(best))
+            .map(|(item, _)| item.clone())
+            .collect(),
+        None => vec![],
     }
-}
\ No newline at end of file
+}
+
+/// The outcome of a showdown between hand strings.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum WinResult<'a> {
+    /// This hand wins alone, with this rank.
+    Winner(&'a str, Rank),
+    /// These hands tie for the pot, all with this rank.
+    SplitPot(Vec<&'a str>, Rank),
+}
+
+/// Like [`winning_hands`], but says whether the pot is split and with which rank it is won.
+///
+/// Fails on the first invalid hand. An empty list of hands has no winner and fails with
+/// [`HandParseError::WrongCardCount`] of 0.
+pub fn determine_winner<'a>(hands: &[&'a str]) -> Result<WinResult<'a>, HandParseError> {
+    let parsed = hands.iter().map(|&h| Hand::from_str(h)).collect::<Result<Vec<_>, _>>()?;
+    let rank = parsed.iter().max().ok_or(HandParseError::WrongCardCount(0))?.rank;
+    let mut winners = winners(hands, &parsed);
+    Ok(match winners.len() {
+        1 => WinResult::Winner(winners.remove(0), rank),
+        _ => WinResult::SplitPot(winners, rank),
+    })
+}
+
+/// Like [`winning_hands`], for hands written on one line and split by `hand_separator`.
+///
+/// The winners are trimmed slices of `line`. An empty segment is an error.
+pub fn winning_hands_from_line(line: &str, hand_separator: char) -> Result<Vec<&str>, HandParseError> {
+    let hands = line.split(hand_separator).map(str::trim).collect::<Vec<_>>();
+    let parsed = hands.iter()
+        .map(|&h| if h.is_empty() { Err(HandParseError::WrongCardCount(0)) } else { Hand::from_str(h) })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(winners(&hands, &parsed))
+}
+
+/// Like [`winning_hands`], for hands from any iterator.
+pub fn winning_hands_from_iter<'a>(hands: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    winning_hands(&hands.collect::<Vec<_>>())
+}
+
+/// The best hand(s) out of one hand per line of `reader`, in their input order, keeping only
+/// the best hands seen so far. Blank lines are skipped; an invalid hand is an
+/// [`io::ErrorKind::InvalidData`] error wrapping its [`HandParseError`].
+pub fn winning_hands_from_reader(reader: impl BufRead) -> io::Result<Vec<String>> {
+    let mut best: Option<Hand<'static>> = None;
+    let mut winners = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() { continue }
+        let hand = Hand::try_from(line.trim().to_string())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        match best.as_ref().map(|b| hand.cmp(b)) {
+            Some(Ordering::Less) => {}
+            Some(Ordering::Equal) => winners.push(hand.source().to_string()),
+            _ => {
+                winners = vec![hand.source().to_string()];
+                best = Some(hand);
+            }
+        }
+    }
+    Ok(winners)
+}
+
+/// Like [`winning_hands`], but gives up and returns `None` as soon as `cancel` is set.
+pub fn winning_hands_with_cancel<'a>(hands: &[&'a str], cancel: &AtomicBool) -> Option<Vec<&'a str>> {
+    let mut parsed = Vec::with_capacity(hands.len());
+    for &h in hands {
+        if cancel.load(AtomicOrdering::Relaxed) { return None }
+        parsed.push(Hand::from_str(h).unwrap_or_else(|_| panic!("Error, check input string")));
+    }
+    Some(winners(hands, &parsed))
+}
+
+/// Same as [`winning_hands`], by sorting every hand first: O(n log n) instead of one pass.
+/// Kept as the reference the linear version is checked against.
+///
+/// Panics if one of the strings is not a valid hand.
+pub fn winning_hands_sorted<'a>(hands: &[&'a str]) -> Vec<&'a str> {
+    let parsed: Result<Vec<_>, _> = hands.iter().map(|&h| Hand::from_str(h)).collect();
+    if parsed.is_err() { panic!("Error, check input string") }
+    let mut hands = hands.iter().copied().zip(parsed.unwrap()).collect::<Vec<_>>();
+    // best first; the sort is stable, so tied hands keep their input order
+    hands.sort_by(|(_, h1), (_, h2)| h2.cmp(h1));
+    match hands.first() {
+        Some((_, best)) => hands.iter().filter(|(_, h)| h.ties_with(best)).map(|&(src, _)| src).collect(),
+        None => vec![],
+    }
+}
@@ -0,0 +1,74 @@
+//! Pai Gow poker, where seven cards are split into a five card hand and a two card hand.
+
+use std::cmp::Ordering;
+
+use crate::{distinct_cards, for_each_combination, parse_cards, Card, CardValue, Hand, HandParseError, Rank};
+
+/// The rank of a two card hand: any pair beats any two unpaired cards.
+///
+/// Pairs compare by value, unpaired cards by the high card then the low card.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum TwoCardRank {
+    /// Two different values, the high one first.
+    HighCard(CardValue, CardValue),
+    /// Two cards of this value.
+    Pair(CardValue),
+}
+
+/// The rank of the two card hand made of `a` and `b`.
+pub fn eval_two_card_hand(a: Card, b: Card) -> TwoCardRank {
+    let (high, low) = if a.value() >= b.value() { (a.value(), b.value()) } else { (b.value(), a.value()) };
+    if high == low { TwoCardRank::Pair(high) } else { TwoCardRank::HighCard(high, low) }
+}
+
+/// Picks the best two card hand(s) out of a list of two card hand strings.
+///
+/// Panics if one of the strings is not two valid cards.
+pub fn winning_two_card_hands<'a>(hands: &[&'a str]) -> Vec<&'a str> {
+    let ranks = hands.iter()
+        .map(|&h| match parse_cards(h).as_deref() {
+            Ok(&[a, b]) => eval_two_card_hand(a, b),
+            _ => panic!("Error, check input string"),
+        })
+        .collect::<Vec<_>>();
+    match ranks.iter().max() {
+        Some(best) => hands.iter().zip(&ranks)
+            .filter(|&(_, r)| r == best)
+            .map(|(&src, _)| src)
+            .collect(),
+        None => vec![],
+    }
+}
+
+// whether a five card hand is at least as strong as a two card hand, its extra cards breaking ties
+fn outranks(high: &Hand, low: TwoCardRank) -> bool {
+    let values = high.tiebreaker_values();
+    match (high.rank, low) {
+        (Rank::HighCard, TwoCardRank::Pair(_)) => false,
+        (Rank::HighCard, TwoCardRank::HighCard(h, l)) => (values[0], values[1]) >= (h, l),
+        (Rank::OnePair, TwoCardRank::Pair(v)) => values[0] >= v,
+        _ => true,
+    }
+}
+
+/// Splits seven cards into the best five card hand that still beats the other two cards.
+///
+/// Ties between five card hands go to the split with the best two card hand.
+pub fn optimal_pai_gow_split(seven_cards: &str) -> Result<(Hand<'static>, [Card; 2]), HandParseError> {
+    let cards = parse_cards(seven_cards)?;
+    if cards.len() != 7 { return Err(HandParseError::WrongCardCount(cards.len())) }
+    distinct_cards(&cards)?;
+    let mut best = None::<(Hand<'static>, [Card; 2])>;
+    for_each_combination(&cards, 5, &mut |five| {
+        let mut rest = cards.iter().filter(|c| !five.contains(c));
+        let low = [*rest.next().expect("two cards left"), *rest.next().expect("two cards left")];
+        let high = Hand::from_cards(five).expect("five distinct cards");
+        let low_rank = eval_two_card_hand(low[0], low[1]);
+        if !outranks(&high, low_rank) { return }
+        let better = best.as_ref().is_none_or(|(h, [a, b])| {
+            high.cmp(h).then(low_rank.cmp(&eval_two_card_hand(*a, *b))) != Ordering::Less
+        });
+        if better { best = Some((high, low)) }
+    });
+    Ok(best.expect("some split keeps the five card hand above the two card hand"))
+}
@@ -0,0 +1,150 @@
+//! PokerStars hand histories.
+//!
+//! Only the lines naming cards are read: `Dealt to Hero [Ah Kd]`,
+//! `Villain: shows [Qs Qd]` and `Board [2s 3h 4d 5c 6h]`. Every other line is skipped.
+
+use crate::{best_hand, distinct_cards, winners, Card, CardParseError, CardSuit, CardValue, Hand, HandParseError};
+
+/// The hole cards of one player.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DealtHand {
+    /// The player name, as written in the hand history.
+    pub player: String,
+    /// The two cards dealt to the player.
+    pub hole_cards: [Card; 2],
+}
+
+/// The board and the known hole cards of a hand, with its winners.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PokerHandResult {
+    /// The three to five community cards.
+    pub board: Vec<Card>,
+    /// The players whose hole cards are known, in order of appearance.
+    pub player_hands: Vec<DealtHand>,
+    /// The players with the best hand among `player_hands`.
+    pub winners: Vec<String>,
+}
+
+/// Error returned when a hand history cannot be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// There is no `Board [...]` line.
+    MissingBoard,
+    /// This `PokerStars Hand #...` line has no hand number.
+    InvalidHeader(String),
+    /// This line has a `[` without a matching `]`.
+    UnclosedBracket(String),
+    /// A card list is not valid.
+    InvalidCards(HandParseError),
+}
+
+impl From<HandParseError> for ParseError {
+    fn from(e: HandParseError) -> ParseError {
+        ParseError::InvalidCards(e)
+    }
+}
+
+// a card as written by PokerStars: `Ah`, `Td`, and also `10d`
+fn parse_card(s: &str) -> Result<Card, CardParseError> {
+    let mut chars = s.chars();
+    let suit = chars.next_back().ok_or_else(|| CardParseError::TooShort(s.to_string()))?;
+    let suit = CardSuit::try_from(suit).map_err(|_| CardParseError::InvalidSuit(suit))?;
+    let value = chars.as_str();
+    let invalid = || CardParseError::InvalidValue(value.to_string());
+    let value = match value {
+        "10" => CardValue::Ten,
+        v => {
+            let mut v = v.chars();
+            match (v.next(), v.next()) {
+                (Some(c), None) => CardValue::from_char(c.to_ascii_uppercase()).ok_or_else(invalid)?,
+                _ => return Err(invalid()),
+            }
+        }
+    };
+    Ok(Card::new(value, suit))
+}
+
+// cards written without separators, like `"AsKs"` or `"10dAs"`, each ending at its suit;
+// spaces are allowed too
+pub(crate) fn parse_compact_cards(s: &str) -> Result<Vec<Card>, HandParseError> {
+    let mut cards = Vec::new();
+    let mut card = String::new();
+    for c in s.chars().filter(|c| !c.is_whitespace()) {
+        card.push(c);
+        if CardSuit::try_from(c).is_ok() {
+            cards.push(parse_card(&card)?);
+            card.clear();
+        }
+    }
+    if !card.is_empty() { cards.push(parse_card(&card)?) }
+    Ok(cards)
+}
+
+// the cards between the brackets of a line, if it has some
+fn bracketed_cards(line: &str) -> Result<Option<Vec<Card>>, ParseError> {
+    let Some(open) = line.find('[') else { return Ok(None) };
+    let close = line[open..].find(']').ok_or_else(|| ParseError::UnclosedBracket(line.to_string()))?;
+    let cards = line[open + 1..open + close]
+        .split_whitespace()
+        .map(parse_card)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(HandParseError::from)?;
+    Ok(Some(cards))
+}
+
+fn hole_cards(cards: Vec<Card>) -> Result<[Card; 2], HandParseError> {
+    let n = cards.len();
+    cards.try_into().map_err(|_| HandParseError::WrongCardCount(n))
+}
+
+// the board, if any, and the hole cards named in the lines of one hand
+pub(crate) fn read_cards(s: &str) -> Result<(Option<Vec<Card>>, Vec<DealtHand>), ParseError> {
+    let mut board = None;
+    let mut player_hands: Vec<DealtHand> = Vec::new();
+    for line in s.lines().map(str::trim) {
+        let player = if let Some(rest) = line.strip_prefix("Dealt to ") {
+            rest.split(" [").next().unwrap_or(rest).to_string()
+        } else if let Some((name, _)) = line.split_once(": shows [") {
+            name.to_string()
+        } else if line.starts_with("Board [") {
+            let cards = bracketed_cards(line)?.unwrap_or_default();
+            if !(3..=5).contains(&cards.len()) {
+                return Err(HandParseError::WrongCardCount(cards.len()).into())
+            }
+            board = Some(cards);
+            continue
+        } else {
+            continue
+        };
+        let Some(cards) = bracketed_cards(line)? else { continue };
+        if player_hands.iter().any(|h| h.player == player) { continue }
+        player_hands.push(DealtHand { player, hole_cards: hole_cards(cards)? });
+    }
+    Ok((board, player_hands))
+}
+
+// the best hand of each player with the board
+pub(crate) fn best_hands(board: &[Card], player_hands: &[DealtHand]) -> Result<Vec<Hand<'static>>, ParseError> {
+    let mut hands = Vec::with_capacity(player_hands.len());
+    for dealt in player_hands {
+        let mut cards = board.to_vec();
+        cards.extend(dealt.hole_cards);
+        distinct_cards(&cards)?;
+        hands.push(best_hand(&cards));
+    }
+    Ok(hands)
+}
+
+impl PokerHandResult {
+    /// Reads the board and the shown hole cards of a hand history, and finds the winners.
+    ///
+    /// A player both dealt to and showing is only counted once.
+    pub fn from_pokerstars_str(s: &str) -> Result<PokerHandResult, ParseError> {
+        let (board, player_hands) = read_cards(s)?;
+        let board = board.ok_or(ParseError::MissingBoard)?;
+        let hands = best_hands(&board, &player_hands)?;
+        let players = player_hands.iter().map(|dealt| dealt.player.clone()).collect::<Vec<_>>();
+        let winners = winners(&players, &hands);
+        Ok(PokerHandResult { board, player_hands, winners })
+    }
+}
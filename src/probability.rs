@@ -0,0 +1,38 @@
+//! Exact probabilities of the ranks of a random five card hand.
+//!
+//! Counts come from [`Rank::frequency_in_standard_deck`] and are summed as `u64`; only the final
+//! division is done in `f64`, so each result is the closest `f64` to the exact fraction.
+
+use crate::Rank;
+
+// C(52, 5)
+const ALL_HANDS: u64 = 2_598_960;
+
+fn hands_at_least(rank: Rank) -> u64 {
+    Rank::ALL.iter().filter(|&&r| r >= rank).map(Rank::frequency_in_standard_deck).sum()
+}
+
+/// The probability that a random hand has exactly this rank.
+pub fn prob_of_rank(rank: Rank) -> f64 {
+    rank.frequency_in_standard_deck() as f64 / ALL_HANDS as f64
+}
+
+/// The probability that a random hand has this rank or a better one.
+pub fn prob_of_rank_or_better(rank: Rank) -> f64 {
+    hands_at_least(rank) as f64 / ALL_HANDS as f64
+}
+
+/// The probability that a random hand known to be `current` or better is `target` or better,
+/// `None` unless `target` is above `current`.
+pub fn prob_improving_to_rank(current: Rank, target: Rank) -> Option<f64> {
+    if target <= current { return None }
+    Some(hands_at_least(target) as f64 / hands_at_least(current) as f64)
+}
+
+/// The mean rank of a random hand, counting High Card as 0 up to Straight Flush as 8.
+pub fn expected_hand_rank() -> f64 {
+    let total = Rank::ALL.iter().enumerate()
+        .map(|(i, r)| i as u64 * r.frequency_in_standard_deck())
+        .sum::<u64>();
+    total as f64 / ALL_HANDS as f64
+}
@@ -0,0 +1,56 @@
+use std::cmp::Ordering;
+
+use crate::{Hand, HandParseError};
+
+/// A hand with its score computed once, for comparing the same hands over and over.
+///
+/// Ordering and equality only look at the score, so hands of equal strength are equal.
+#[derive(Clone, Debug)]
+pub struct QuickEval {
+    hand: Hand<'static>,
+    score: u32,
+}
+
+impl QuickEval {
+    /// Parses five space separated cards, like [`Hand::from_str`].
+    pub fn new(hand_str: &str) -> Result<QuickEval, HandParseError> {
+        Ok(QuickEval::from(Hand::from_str(hand_str)?.into_owned()))
+    }
+
+    /// The evaluated hand.
+    pub fn hand(&self) -> &Hand<'static> {
+        &self.hand
+    }
+
+    /// Same as [`Hand::score`].
+    pub fn score(&self) -> u32 {
+        self.score
+    }
+}
+
+impl From<Hand<'static>> for QuickEval {
+    fn from(hand: Hand<'static>) -> QuickEval {
+        let score = hand.score();
+        QuickEval { hand, score }
+    }
+}
+
+impl PartialEq for QuickEval {
+    fn eq(&self, other: &Self) -> bool {
+        self.score == other.score
+    }
+}
+
+impl Eq for QuickEval {}
+
+impl PartialOrd for QuickEval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QuickEval {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score.cmp(&other.score)
+    }
+}
@@ -0,0 +1,118 @@
+//! Whole PokerStars hand history files.
+//!
+//! A file holds one block per hand, each starting with a line like
+//! `PokerStars Hand #210000000001:  Hold'em No Limit ($0.01/$0.02 USD) - 2020/03/01 12:00:00 ET`.
+//! The cards are read as in [`pokerstars`](crate::pokerstars); the winners are the players
+//! who `collected` from a pot.
+
+use crate::pokerstars::{best_hands, read_cards, DealtHand, ParseError};
+use crate::{Card, Hand};
+
+/// One hand of a hand history file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandHistoryRecord {
+    /// The number after `PokerStars Hand #`.
+    pub hand_number: u64,
+    /// The game as written in the header, like `Hold'em No Limit`.
+    pub game_type: String,
+    /// The stakes between the parentheses of the header, like `$0.01/$0.02 USD`; empty when absent.
+    pub stakes: String,
+    /// The players whose hole cards are known, in order of appearance.
+    pub player_hands: Vec<DealtHand>,
+    /// The community cards, empty when the hand ended before the flop.
+    pub board: Vec<Card>,
+    /// The players who collected from a pot, in order of appearance.
+    pub winners: Vec<String>,
+    /// The amount after `Total pot`, as written, like `$0.40`; empty when absent.
+    pub pot: String,
+}
+
+/// The best hand a player showed down.
+///
+/// Results are equal when they hold the same cards, not only hands of the same strength.
+#[derive(Debug, Clone)]
+pub struct ShowdownResult {
+    /// The player name.
+    pub player: String,
+    /// The best five cards of the hole cards and the board.
+    pub best_hand: Hand<'static>,
+    /// Whether no other shown hand beats this one.
+    pub wins: bool,
+}
+
+impl PartialEq for ShowdownResult {
+    fn eq(&self, other: &ShowdownResult) -> bool {
+        self.player == other.player && self.best_hand.to_vec() == other.best_hand.to_vec() && self.wins == other.wins
+    }
+}
+
+impl Eq for ShowdownResult {}
+
+/// Reads every hand of a hand history file. Text before the first hand is skipped.
+pub fn parse_hand_history_file(content: &str) -> Result<Vec<HandHistoryRecord>, ParseError> {
+    let mut blocks = Vec::new();
+    let mut start = None;
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        if line.trim_start().starts_with("PokerStars Hand #") {
+            if let Some(s) = start { blocks.push(&content[s..offset]) }
+            start = Some(offset);
+        }
+        offset += line.len();
+    }
+    if let Some(s) = start { blocks.push(&content[s..]) }
+    blocks.into_iter().map(HandHistoryRecord::from_block).collect()
+}
+
+impl HandHistoryRecord {
+    // one hand, its header on the first line
+    fn from_block(block: &str) -> Result<HandHistoryRecord, ParseError> {
+        let header = block.lines().next().unwrap_or_default().trim();
+        let invalid = || ParseError::InvalidHeader(header.to_string());
+        let (number, rest) = header.strip_prefix("PokerStars Hand #")
+            .and_then(|h| h.split_once(':'))
+            .ok_or_else(invalid)?;
+        let hand_number = number.parse().map_err(|_| invalid())?;
+        let description = rest.split(" - ").next().unwrap_or_default().trim();
+        let (game_type, stakes) = match description.split_once('(') {
+            Some((game, stakes)) => (game.trim(), stakes.split(')').next().unwrap_or_default()),
+            None => (description, ""),
+        };
+
+        let (board, player_hands) = read_cards(block)?;
+        let mut winners: Vec<String> = Vec::new();
+        let mut pot = String::new();
+        for line in block.lines().map(str::trim) {
+            if let Some((name, _)) = line.split_once(" collected ") {
+                if !winners.iter().any(|w| w == name) { winners.push(name.to_string()) }
+            } else if let Some(rest) = line.strip_prefix("Total pot ") {
+                pot = rest.split_whitespace().next().unwrap_or_default().to_string();
+            }
+        }
+        Ok(HandHistoryRecord {
+            hand_number,
+            game_type: game_type.to_string(),
+            stakes: stakes.to_string(),
+            player_hands,
+            board: board.unwrap_or_default(),
+            winners,
+            pot,
+        })
+    }
+
+    /// The best hand of every player whose hole cards are known, and which of them win.
+    ///
+    /// Empty when the board has fewer than three cards or the cards repeat.
+    pub fn evaluate_showdown(&self) -> Vec<ShowdownResult> {
+        if self.board.len() < 3 { return vec![] }
+        let Ok(hands) = best_hands(&self.board, &self.player_hands) else { return vec![] };
+        let Some(best) = hands.iter().max().cloned() else { return vec![] };
+        self.player_hands.iter().zip(hands)
+            .map(|(dealt, hand)| ShowdownResult {
+                player: dealt.player.clone(),
+                wins: hand.ties_with(&best),
+                best_hand: hand,
+            })
+            .collect()
+    }
+}
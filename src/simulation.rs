@@ -0,0 +1,158 @@
+//! Monte Carlo equity of a Hold'em hand against a range.
+
+use crate::holdem::parse_range;
+use crate::pokerstars::parse_compact_cards;
+use crate::rng::{Rng, SplitMix64};
+use crate::{deck_card, distinct_cards, for_each_combination, quick_score, Card, HandParseError};
+
+/// Error returned by [`Simulation::run`] when the simulation is not set up right.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildError {
+    /// No hero hand was given.
+    MissingHeroHand,
+    /// The hero hand is not two valid cards.
+    InvalidHeroHand(HandParseError),
+    /// No villain range was given.
+    MissingVillainRange,
+    /// The villain range is not valid.
+    InvalidVillainRange(HandParseError),
+    /// Every holding of the villain range uses a card of the hero or of the board.
+    EmptyVillainRange,
+    /// The board is not zero, three, four or five valid cards distinct from the hero hand.
+    InvalidBoard(HandParseError),
+    /// The number of simulations is zero.
+    NoSimulations,
+}
+
+/// How often the hero hand wins, ties and loses against the villain range.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimulationResult {
+    /// The fraction of deals the hero wins outright.
+    pub win_rate: f64,
+    /// The fraction of deals ending in a split pot.
+    pub tie_rate: f64,
+    /// The fraction of deals the villain wins.
+    pub loss_rate: f64,
+    /// The 95% confidence interval of the win rate, by the normal approximation.
+    pub confidence_interval_95: (f64, f64),
+}
+
+/// Sets up and runs an equity simulation, cards written like `"AsKs"` and `"2h3h4d"`.
+///
+/// ```
+/// use poker::simulation::Simulation;
+///
+/// let result = Simulation::new()
+///     .hero_hand("AsKs")
+///     .villain_range("TT+,AKs,AQs")
+///     .board("2h3h4d")
+///     .n_simulations(1_000)
+///     .seed(42)
+///     .run()
+///     .unwrap();
+/// assert!(result.win_rate > 0.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Simulation {
+    hero_hand: Option<String>,
+    villain_range: Option<String>,
+    board: String,
+    n_simulations: usize,
+    seed: u64,
+}
+
+impl Default for Simulation {
+    fn default() -> Simulation {
+        Simulation::new()
+    }
+}
+
+impl Simulation {
+    /// A simulation of 10,000 deals with seed 0, no board, and no hands yet.
+    pub fn new() -> Simulation {
+        Simulation { hero_hand: None, villain_range: None, board: String::new(), n_simulations: 10_000, seed: 0 }
+    }
+
+    /// The two hole cards of the hero, like `"AsKs"`.
+    pub fn hero_hand(mut self, cards: &str) -> Simulation {
+        self.hero_hand = Some(cards.to_string());
+        self
+    }
+
+    /// The holdings of the villain, like `"TT+,AKs"`; see [`parse_range`].
+    pub fn villain_range(mut self, range: &str) -> Simulation {
+        self.villain_range = Some(range.to_string());
+        self
+    }
+
+    /// The community cards already dealt, like `"2h3h4d"`.
+    pub fn board(mut self, cards: &str) -> Simulation {
+        self.board = cards.to_string();
+        self
+    }
+
+    /// How many deals to simulate.
+    pub fn n_simulations(mut self, n: usize) -> Simulation {
+        self.n_simulations = n;
+        self
+    }
+
+    /// The seed of the random deals, the same seed giving the same result.
+    pub fn seed(mut self, seed: u64) -> Simulation {
+        self.seed = seed;
+        self
+    }
+
+    /// Checks the setup, then deals a villain holding and the rest of the board
+    /// `n_simulations` times.
+    pub fn run(&self) -> Result<SimulationResult, BuildError> {
+        let hero = parse_compact_cards(self.hero_hand.as_deref().ok_or(BuildError::MissingHeroHand)?)
+            .map_err(BuildError::InvalidHeroHand)?;
+        if hero.len() != 2 { return Err(BuildError::InvalidHeroHand(HandParseError::WrongCardCount(hero.len()))) }
+        distinct_cards(&hero).map_err(BuildError::InvalidHeroHand)?;
+        let board = parse_compact_cards(&self.board).map_err(BuildError::InvalidBoard)?;
+        if ![0, 3, 4, 5].contains(&board.len()) {
+            return Err(BuildError::InvalidBoard(HandParseError::WrongCardCount(board.len())))
+        }
+        let known = [&hero[..], &board[..]].concat();
+        distinct_cards(&known).map_err(BuildError::InvalidBoard)?;
+        let range = parse_range(self.villain_range.as_deref().ok_or(BuildError::MissingVillainRange)?)
+            .map_err(BuildError::InvalidVillainRange)?;
+        let range = range.into_iter().filter(|h| !h.iter().any(|c| known.contains(c))).collect::<Vec<_>>();
+        if range.is_empty() { return Err(BuildError::EmptyVillainRange) }
+        if self.n_simulations == 0 { return Err(BuildError::NoSimulations) }
+
+        let mut rng = SplitMix64::new(self.seed);
+        let (mut wins, mut ties) = (0, 0);
+        for _ in 0..self.n_simulations {
+            let villain = range[rng.below(range.len())];
+            let mut deck = (0..52).map(deck_card)
+                .filter(|c| !known.contains(c) && !villain.contains(c))
+                .collect::<Vec<_>>();
+            let mut full_board = board.clone();
+            while full_board.len() < 5 {
+                full_board.push(deck.swap_remove(rng.below(deck.len())));
+            }
+            let hero_score = best_score(&hero, &full_board);
+            let villain_score = best_score(&villain, &full_board);
+            if hero_score > villain_score { wins += 1 } else if hero_score == villain_score { ties += 1 }
+        }
+        let n = self.n_simulations as f64;
+        let win_rate = wins as f64 / n;
+        let margin = 1.96 * (win_rate * (1.0 - win_rate) / n).sqrt();
+        Ok(SimulationResult {
+            win_rate,
+            tie_rate: ties as f64 / n,
+            loss_rate: (self.n_simulations - wins - ties) as f64 / n,
+            confidence_interval_95: ((win_rate - margin).max(0.0), (win_rate + margin).min(1.0)),
+        })
+    }
+}
+
+// the score of the best five cards out of two hole cards and a five card board
+fn best_score(hole: &[Card], board: &[Card]) -> u32 {
+    let cards = [hole, board].concat();
+    let mut best = 0;
+    for_each_combination(&cards, 5, &mut |five| best = best.max(quick_score(five)));
+    best
+}
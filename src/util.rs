@@ -0,0 +1,29 @@
+//! Small helpers over slices of cards.
+
+use std::cmp::Ordering;
+
+use crate::{Card, CardSuit, CardValue};
+
+/// How many of `cards` are of this suit.
+pub fn suit_count(suit: CardSuit, cards: &[Card]) -> usize {
+    cards.iter().filter(|c| c.suit == suit).count()
+}
+
+/// The values of `cards`, each once, highest first.
+pub fn distinct_values(cards: &[Card]) -> Vec<CardValue> {
+    let mut values = cards.iter().map(|c| c.value).collect::<Vec<_>>();
+    values.sort_unstable_by(|a, b| b.cmp(a));
+    values.dedup();
+    values
+}
+
+/// The highest value of `cards`, `None` when there are none.
+pub fn max_value(cards: &[Card]) -> Option<CardValue> {
+    cards.iter().map(|c| c.value).max()
+}
+
+/// Compares two lists of kickers value by value, the first difference deciding;
+/// when one list is the start of the other, the longer one is greater.
+pub fn cmp_kicker_chain(kickers_a: &[CardValue], kickers_b: &[CardValue]) -> Ordering {
+    kickers_a.cmp(kickers_b)
+}
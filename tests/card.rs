@@ -0,0 +1,201 @@
+use poker::{Card, CardSuit, CardValue};
+
+#[test]
+fn test_with_value_keeps_suit() {
+    let card = Card::new(CardValue::Two, CardSuit::Heart);
+    assert_eq!(card.with_value(CardValue::Ace), Card::new(CardValue::Ace, CardSuit::Heart))
+}
+
+#[test]
+fn test_with_suit_keeps_value() {
+    let card = Card::new(CardValue::King, CardSuit::Club);
+    assert_eq!(card.with_suit(CardSuit::Spade), Card::new(CardValue::King, CardSuit::Spade))
+}
+
+#[test]
+fn test_accessors_return_fields() {
+    let card = Card::new(CardValue::Ten, CardSuit::Diamond);
+    assert_eq!(card.value(), CardValue::Ten);
+    assert_eq!(card.suit(), CardSuit::Diamond);
+}
+
+#[test]
+fn test_card_from_and_into_pair() {
+    let card: Card = (CardValue::Ace, CardSuit::Spade).into();
+    assert_eq!(card, Card::from_pair(CardValue::Ace, CardSuit::Spade));
+    let (value, suit) = card.into();
+    assert_eq!((value, suit), (CardValue::Ace, CardSuit::Spade));
+}
+
+#[test]
+fn test_value_from_char() {
+    assert_eq!(CardValue::from_char('2'), Some(CardValue::Two));
+    assert_eq!(CardValue::from_char('9'), Some(CardValue::Nine));
+    assert_eq!(CardValue::from_char('T'), Some(CardValue::Ten));
+    assert_eq!(CardValue::from_char('A'), Some(CardValue::Ace));
+    assert_eq!(CardValue::from_char('1'), None);
+    assert_eq!(CardValue::from_char('0'), None);
+    assert_eq!(CardValue::from_char('X'), None);
+}
+
+#[test]
+fn test_value_u8_round_trip() {
+    for n in 2..=14u8 {
+        let value = CardValue::try_from(n).unwrap();
+        assert_eq!(u8::from(value), n);
+        assert_eq!(usize::from(value), n as usize);
+    }
+    assert_eq!(u8::from(CardValue::Ace), 14);
+    assert_eq!(u8::from(CardValue::Two), 2);
+}
+
+#[test]
+fn test_value_try_from_out_of_range() {
+    use poker::InvalidCardValue;
+    assert_eq!(CardValue::try_from(1), Err(InvalidCardValue(1)));
+    assert_eq!(CardValue::try_from(15), Err(InvalidCardValue(15)));
+}
+
+#[test]
+fn test_suit_char_conversions() {
+    use poker::InvalidSuitChar;
+    assert_eq!(CardSuit::try_from('H'), Ok(CardSuit::Heart));
+    assert_eq!(CardSuit::try_from('d'), Ok(CardSuit::Diamond));
+    assert_eq!(CardSuit::try_from('♠'), Ok(CardSuit::Spade));
+    assert_eq!(CardSuit::try_from('X'), Err(InvalidSuitChar('X')));
+    assert_eq!(char::from(CardSuit::Club), 'C');
+    assert_eq!(<&str>::from(CardSuit::Spade), "S");
+}
+
+const ACE_OF_SPADES: Card = Card::new(CardValue::Ace, CardSuit::Spade);
+const KING_OF_HEARTS: Card = Card::new_const(CardValue::King, CardSuit::Heart);
+const ACE_VALUE: u8 = CardValue::Ace.to_u8();
+const SPADE_INDEX: u8 = CardSuit::Spade.to_u8();
+
+#[test]
+fn test_const_constructors() {
+    assert_eq!(ACE_OF_SPADES, Card::new(CardValue::Ace, CardSuit::Spade));
+    assert_eq!(KING_OF_HEARTS, Card::new(CardValue::King, CardSuit::Heart));
+    assert_eq!(ACE_VALUE, 14);
+    assert_eq!(SPADE_INDEX, 3);
+    assert_eq!(CardSuit::Club.to_u8(), 0);
+}
+
+#[test]
+fn test_broadway_and_wheel_values() {
+    let broadway = CardValue::broadway_values();
+    assert!(broadway.iter().all(|v| v.is_broadway()));
+    assert_eq!(broadway.map(u8::from), [10, 11, 12, 13, 14]);
+    assert_eq!(CardValue::wheel_values().map(CardValue::lowball_rank), [1, 2, 3, 4, 5]);
+    assert!(!CardValue::Nine.is_broadway());
+}
+
+#[test]
+fn test_card_from_str() {
+    use poker::CardParseError;
+
+    assert_eq!("10H".parse::<Card>(), Ok(Card::new(CardValue::Ten, CardSuit::Heart)));
+    assert_eq!("QS".parse::<Card>(), Ok(Card::new(CardValue::Queen, CardSuit::Spade)));
+    assert_eq!("".parse::<Card>(), Err(CardParseError::TooShort(String::new())));
+    assert_eq!("QX".parse::<Card>(), Err(CardParseError::InvalidSuit('X')));
+    assert_eq!("1H".parse::<Card>(), Err(CardParseError::InvalidValue("1".to_string())));
+    assert_eq!("11H".parse::<Card>(), Err(CardParseError::InvalidValue("11".to_string())));
+    assert!(matches!("QQH".parse::<Card>(), Err(CardParseError::NotANumber(v, _)) if v == "QQ"));
+}
+
+#[test]
+fn test_parse_errors_are_errors() {
+    use poker::{CardParseError, HandParseError};
+    use std::error::Error;
+
+    let suit = "QX".parse::<Card>().unwrap_err();
+    assert_eq!(suit.to_string(), "Invalid card suit: 'X' — expected C, D, H, or S");
+    assert!(suit.source().is_none());
+    let number = "QQH".parse::<Card>().unwrap_err();
+    assert!(matches!(number, CardParseError::NotANumber(..)));
+    assert!(number.source().is_some());
+
+    let boxed: Box<dyn Error> = Box::new(HandParseError::WrongCardCount(4));
+    assert_eq!(boxed.to_string(), "Wrong card count: 4");
+    let card = HandParseError::from(suit);
+    assert_eq!(card.to_string(), "Invalid card: Invalid card suit: 'X' — expected C, D, H, or S");
+    assert!(card.source().is_some());
+}
+
+#[test]
+fn test_poker_and_lowball_rank() {
+    assert_eq!(CardValue::Ace.poker_rank(), 14);
+    assert_eq!(CardValue::Ace.lowball_rank(), 1);
+    assert_eq!(CardValue::King.poker_rank(), 13);
+    assert_eq!(CardValue::King.lowball_rank(), 13);
+    assert_eq!(CardValue::Two.poker_rank(), 2);
+    assert_eq!(CardValue::Two.lowball_rank(), 2);
+}
+
+#[test]
+fn test_kicker_comparison_ignores_suits() {
+    use std::cmp::Ordering;
+
+    let king_of_spades = Card::new(CardValue::King, CardSuit::Spade);
+    let king_of_clubs = Card::new(CardValue::King, CardSuit::Club);
+    let queen_of_spades = Card::new(CardValue::Queen, CardSuit::Spade);
+    assert!(king_of_spades > king_of_clubs);
+    assert_eq!(king_of_spades.kicker_cmp(&king_of_clubs), Ordering::Equal);
+    assert!(!king_of_spades.beats_as_kicker(&king_of_clubs));
+    assert!(king_of_clubs.beats_as_kicker(&queen_of_spades));
+    assert_eq!(queen_of_spades.kicker_cmp(&king_of_clubs), Ordering::Less);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_beats_for_kicker() {
+    let king_of_spades = Card::new(CardValue::King, CardSuit::Spade);
+    let king_of_clubs = Card::new(CardValue::King, CardSuit::Club);
+    let queen_of_spades = Card::new(CardValue::Queen, CardSuit::Spade);
+    assert!(!king_of_spades.beats_for_kicker(&king_of_clubs));
+    assert!(king_of_clubs.beats_for_kicker(&queen_of_spades));
+    assert!(!queen_of_spades.beats_for_kicker(&king_of_clubs));
+}
+
+#[test]
+fn test_same_value_suit_and_color() {
+    use poker::Color;
+
+    let king_of_hearts = Card::new(CardValue::King, CardSuit::Heart);
+    let king_of_spades = Card::new(CardValue::King, CardSuit::Spade);
+    let two_of_diamonds = Card::new(CardValue::Two, CardSuit::Diamond);
+    assert!(king_of_hearts.same_value(&king_of_spades));
+    assert!(!king_of_hearts.same_suit(&king_of_spades));
+    assert!(!king_of_hearts.same_color(&king_of_spades));
+    assert!(king_of_hearts.same_color(&two_of_diamonds));
+    assert!(!king_of_hearts.same_value(&two_of_diamonds));
+    assert!(king_of_hearts.same_suit(&king_of_hearts.with_value(CardValue::Two)));
+    assert_eq!(CardSuit::Club.color(), Color::Black);
+    assert_eq!(CardSuit::Diamond.color(), Color::Red);
+}
+
+#[test]
+fn test_card_from_str_lengths() {
+    use poker::CardParseError;
+
+    assert_eq!("H".parse::<Card>(), Err(CardParseError::TooShort("H".to_string())));
+    assert_eq!("10HH".parse::<Card>(), Err(CardParseError::TooLong("10HH".to_string())));
+    assert_eq!("2C".parse::<Card>(), Ok(Card::new(CardValue::Two, CardSuit::Club)));
+    assert_eq!("10C".parse::<Card>(), Ok(Card::new(CardValue::Ten, CardSuit::Club)));
+    // the last character is always the suit
+    assert_eq!("10".parse::<Card>(), Err(CardParseError::InvalidSuit('0')));
+    assert_eq!("♠".parse::<Card>(), Err(CardParseError::TooShort("♠".to_string())));
+}
+
+#[test]
+fn test_suit_unicode() {
+    for suit in [CardSuit::Club, CardSuit::Diamond, CardSuit::Heart, CardSuit::Spade] {
+        assert_eq!(CardSuit::from_unicode(suit.to_unicode()), Some(suit));
+        assert!(suit.to_unicode_emoji().starts_with(suit.to_unicode()));
+    }
+    assert_eq!(CardSuit::from_unicode('♡'), Some(CardSuit::Heart));
+    assert_eq!(CardSuit::from_unicode('♤'), Some(CardSuit::Spade));
+    assert_eq!(CardSuit::from_unicode('S'), None);
+    assert_eq!(CardSuit::Club.to_unicode_emoji(), "\u{2663}\u{fe0f}");
+    assert_eq!(CardSuit::try_from('♢'), Ok(CardSuit::Diamond));
+}
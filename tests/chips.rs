@@ -0,0 +1,21 @@
+use poker::chips::{ChipStack, InsufficientFunds, Pot};
+
+#[test]
+fn test_chip_stack_bets() {
+    let mut stack = ChipStack::new(100);
+    assert_eq!(stack.bet(40), Ok(()));
+    assert_eq!(stack.chips(), 60);
+    assert_eq!(stack.bet(61), Err(InsufficientFunds { needed: 61, available: 60 }));
+    assert_eq!(stack.chips(), 60);
+    stack.win(15);
+    assert_eq!(stack.chips(), 75);
+}
+
+#[test]
+fn test_pot_split_between_winners() {
+    let pot = Pot::new(100, vec![0, 2, 3]);
+    assert_eq!(pot.award_to_winners(&[2]), vec![(2, 100)]);
+    assert_eq!(pot.award_to_winners(&[3, 0, 2]), vec![(0, 34), (2, 33), (3, 33)]);
+    assert_eq!(pot.award_to_winners(&[1, 3]), vec![(3, 100)]);
+    assert!(pot.award_to_winners(&[1]).is_empty());
+}
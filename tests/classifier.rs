@@ -0,0 +1,36 @@
+use poker::classifier::{HandClassifier, LowballClassifier, ShortDeckClassifier, StandardClassifier};
+use poker::Hand;
+
+fn hand(s: &str) -> Hand<'_> {
+    Hand::from_str(s).unwrap()
+}
+
+#[test]
+fn test_standard_classifier_matches_hand_strength() {
+    let flush = hand("2H 6H 9H KH 3H");
+    assert_eq!(flush.classify_with(&StandardClassifier), flush.strength());
+}
+
+#[test]
+fn test_short_deck_classifier() {
+    let flush = hand("6H 8H 9H KH JH");
+    let full_house = hand("6S 6D 6C KS KD");
+    assert!(flush < full_house);
+    assert!(flush.classify_with(&ShortDeckClassifier) > full_house.classify_with(&ShortDeckClassifier));
+    let low_straight = hand("AS 6D 7C 8S 9D");
+    let ten_high = hand("10S 6D 7C 8S 9D");
+    let trips = hand("9S 9D 9C 8S 7D");
+    assert!(low_straight.classify_with(&ShortDeckClassifier) < ten_high.classify_with(&ShortDeckClassifier));
+    assert!(low_straight.classify_with(&ShortDeckClassifier) > trips.classify_with(&ShortDeckClassifier));
+}
+
+#[test]
+fn test_lowball_classifier() {
+    let wheel = hand("AS 2D 3C 4S 5H");
+    let six_low = hand("AS 2D 3C 4S 6H");
+    let pair = hand("AS AD 3C 4S 5H");
+    assert!(wheel.classify_with(&LowballClassifier) > six_low.classify_with(&LowballClassifier));
+    assert!(six_low.classify_with(&LowballClassifier) > pair.classify_with(&LowballClassifier));
+    let dynamic: &dyn HandClassifier<Rank = u32> = &ShortDeckClassifier;
+    assert_eq!(dynamic.classify(&wheel.as_sorted_array()), ShortDeckClassifier.classify(&wheel.as_sorted_array()));
+}
@@ -0,0 +1,121 @@
+use poker::rng::SplitMix64;
+use poker::{Card, Deck, DeckError, Hand, Player, PokerGame, Street};
+use std::collections::BTreeSet;
+
+#[test]
+fn test_shuffled_deck_keeps_all_cards() {
+    let mut deck = Deck::new();
+    deck.shuffle(&mut SplitMix64::new(7));
+    assert_ne!(deck, Deck::new());
+    assert_eq!(deck.remaining().iter().collect::<BTreeSet<_>>().len(), 52);
+}
+
+#[test]
+fn test_deal_a_full_hand() {
+    let players = vec![Player::new("Ann", 100), Player::new("Bob", 100), Player::new("Cid", 100)];
+    let mut game = PokerGame::new(players, 42);
+    game.deal_hole_cards();
+    assert_eq!(game.street(), Street::Preflop);
+    assert!(game.bet(0, 10));
+    assert!(!game.bet(1, 101));
+    game.deal_flop();
+    assert_eq!(game.community().len(), 3);
+    game.deal_turn();
+    game.deal_river();
+    assert_eq!(game.street(), Street::River);
+    assert_eq!(game.pot(), 10);
+    assert_eq!(game.players()[0].stack, 90);
+
+    let mut dealt = game.community().to_vec();
+    for p in game.players() {
+        dealt.extend_from_slice(&p.hole_cards.unwrap());
+    }
+    assert_eq!(dealt.iter().collect::<BTreeSet<_>>().len(), 11);
+
+    let winners = game.showdown();
+    assert!(!winners.is_empty() && winners.iter().all(|&i| i < 3));
+}
+
+#[test]
+#[should_panic]
+fn test_cannot_deal_the_flop_first() {
+    let mut game = PokerGame::new(vec![Player::new("Ann", 100)], 1);
+    game.deal_flop();
+}
+
+#[test]
+fn test_deal_specific_then_missing_cards() {
+    let hand = Hand::from_str("4S 5H 4C 8D 4H").unwrap();
+    let cards = (&hand).into_iter().copied().collect::<Vec<Card>>();
+    let mut deck = Deck::new();
+    assert!(deck.missing_cards(&hand).is_empty());
+    assert_eq!(deck.deal_specific(&cards), Ok(()));
+    assert_eq!(deck.len(), 47);
+    let mut missing = deck.missing_cards(&hand);
+    missing.sort();
+    assert_eq!(missing, cards);
+    assert_eq!(deck.deal_specific(&cards[..1]), Err(DeckError::CardNotInDeck(cards[0])));
+}
+
+#[test]
+fn test_deal_specific_is_all_or_nothing() {
+    let mut deck = Deck::new();
+    let ace = deck.try_deal().unwrap();
+    let king = deck.remaining()[deck.len() - 1];
+    assert_eq!(deck.deal_specific(&[king, ace]), Err(DeckError::CardNotInDeck(ace)));
+    assert!(deck.contains(&king));
+    assert_eq!(deck.deal_specific(&[king, king]), Err(DeckError::CardNotInDeck(king)));
+    assert!(deck.contains(&king));
+}
+
+#[test]
+fn test_try_deal_exhausted() {
+    let mut deck = Deck::new();
+    for _ in 0..52 {
+        assert!(deck.try_deal().is_ok());
+    }
+    assert_eq!(deck.try_deal(), Err(DeckError::DeckExhausted));
+}
+
+#[test]
+fn test_sample_without_replacement() {
+    let mut rng = SplitMix64::new(3);
+    let mut deck = Deck::new();
+    let cards: [Card; 7] = deck.sample_without_replacement(&mut rng);
+    assert_eq!(deck.len(), 45);
+    assert_eq!(cards.iter().collect::<BTreeSet<_>>().len(), 7);
+    assert!(cards.iter().all(|c| !deck.contains(c)));
+    let card = deck.peek_random_card(&mut rng).unwrap();
+    assert!(deck.contains(&card) && deck.len() == 45);
+    let _: [Card; 45] = deck.sample_without_replacement(&mut rng);
+    assert_eq!(deck.peek_random_card(&mut rng), None);
+}
+
+#[test]
+fn test_default_deck_is_full() {
+    #[derive(Default)]
+    struct Table {
+        deck: Deck,
+    }
+    assert_eq!(Table::default().deck, Deck::new());
+    assert_eq!(Deck::default().len(), 52);
+}
+
+#[test]
+fn test_deal_the_largest_table() {
+    let players = (0..PokerGame::MAX_PLAYERS).map(|i| Player::new(&format!("P{i}"), 100)).collect();
+    let mut game = PokerGame::new(players, 5);
+    game.deal_hole_cards();
+    game.deal_flop();
+    game.deal_turn();
+    game.deal_river();
+    assert_eq!(game.community().len(), 5);
+    assert!(!game.showdown().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "a game needs 1 to 22 players, not 23")]
+fn test_too_many_players() {
+    let players = (0..23).map(|i| Player::new(&format!("P{i}"), 100)).collect();
+    PokerGame::new(players, 5);
+}
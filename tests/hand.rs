@@ -0,0 +1,600 @@
+use poker::{all_hands, Card, CardParseError, CardSuit, CardValue, Deck, Hand, HandParseError};
+
+#[test]
+fn test_from_str_rejects_wrong_card_count() {
+    assert_eq!(Hand::from_str("4S 5H 4C 8D").err(), Some(HandParseError::WrongCardCount(4)))
+}
+
+#[test]
+fn test_from_str_rejects_invalid_card() {
+    assert_eq!(
+        Hand::from_str("4S 5H 4C 8D 1H").err(),
+        Some(HandParseError::InvalidCard(CardParseError::InvalidValue("1".to_string()))),
+    )
+}
+
+#[test]
+fn test_flush_suit() {
+    assert_eq!(Hand::from_str("2S 4S 5S 6S 7S").unwrap().flush_suit(), Some(CardSuit::Spade));
+    assert_eq!(Hand::from_str("4H AH 3H 2H 5H").unwrap().flush_suit(), Some(CardSuit::Heart));
+    assert_eq!(Hand::from_str("2S 4S 5S 6S 7H").unwrap().flush_suit(), None);
+}
+
+#[test]
+fn test_strength_orders_like_hands() {
+    let pair = Hand::from_str("2S 4H 6S 4D JH").unwrap();
+    let wheel = Hand::from_str("4D AH 3S 2D 5C").unwrap();
+    let six_high = Hand::from_str("2H 3C 4D 5D 6H").unwrap();
+    assert!(pair.strength() < wheel.strength());
+    assert!(wheel.strength() < six_high.strength());
+}
+
+#[test]
+fn test_beats_ties_with_and_is_weaker_than() {
+    let two_pair = Hand::from_str("2S 8H 2D 8D 3H").unwrap();
+    let same_two_pair = Hand::from_str("2C 8S 2H 8C 3D").unwrap();
+    let trips = Hand::from_str("4S 5H 4C 8S 4H").unwrap();
+    assert!(trips.beats(&two_pair));
+    assert!(two_pair.is_weaker_than(&trips));
+    assert!(two_pair.ties_with(&same_two_pair));
+    assert!(!two_pair.beats(&same_two_pair));
+}
+
+#[test]
+fn test_all_hands_count() {
+    assert_eq!(all_hands().count(), 2_598_960)
+}
+
+#[test]
+fn test_all_hands_are_distinct_cards_in_order() {
+    let first = all_hands().next().unwrap();
+    assert!(first.windows(2).all(|w| w[0] < w[1]));
+    let last = all_hands().last().unwrap();
+    assert_eq!(last[4], Card::new(CardValue::Ace, CardSuit::Spade));
+    assert_eq!(last[0], Card::new(CardValue::King, CardSuit::Spade));
+}
+
+#[test]
+fn test_best_hand_from_seven_cards() {
+    let hand = Hand::best_hand_from_str("2H AS KS 3D QS JS 10S").unwrap();
+    assert_eq!(hand.source(), "AS KS QS JS 10S");
+    assert_eq!(hand.flush_suit(), Some(CardSuit::Spade));
+}
+
+#[test]
+fn test_best_hand_from_six_cards_keeps_the_pair() {
+    let hand = Hand::best_hand_from_str("2H 9C 4S 9D KH 7C").unwrap();
+    assert_eq!(hand.source(), "9C 4S 9D KH 7C");
+}
+
+#[test]
+fn test_best_hand_from_str_rejects_too_many_cards() {
+    let err = Hand::best_hand_from_str("2H 9C 4S 9D KH 7C 3S 5D").err();
+    assert_eq!(err, Some(HandParseError::WrongCardCount(8)))
+}
+
+#[test]
+fn test_replace_card() {
+    let hand = Hand::from_str("4S 5H 4C 8D 4H").unwrap();
+    let four_of_spades = Card::new(CardValue::Four, CardSuit::Spade);
+    let four_of_diamonds = Card::new(CardValue::Four, CardSuit::Diamond);
+    let replaced = hand.replace_card(Card::new(CardValue::Five, CardSuit::Heart), four_of_diamonds).unwrap();
+    assert_eq!(replaced.source(), "4S 4D 4C 8D 4H");
+    assert_eq!(
+        hand.replace_card(Card::new(CardValue::Two, CardSuit::Heart), four_of_diamonds).err(),
+        Some(HandParseError::CardNotInHand(Card::new(CardValue::Two, CardSuit::Heart)))
+    );
+    assert_eq!(
+        hand.replace_card(four_of_spades, Card::new(CardValue::Four, CardSuit::Club)).err(),
+        Some(HandParseError::DuplicateCard(Card::new(CardValue::Four, CardSuit::Club)))
+    );
+}
+
+#[test]
+fn test_discard_and_draw() {
+    let hand = Hand::from_str("4D AH 3S 2D 5C").unwrap();
+    let ace_of_hearts = Card::new(CardValue::Ace, CardSuit::Heart);
+    let five_of_clubs = Card::new(CardValue::Five, CardSuit::Club);
+    let drawn = [Card::new(CardValue::King, CardSuit::Spade), Card::new(CardValue::Queen, CardSuit::Spade)];
+    let redrawn = hand.discard_and_draw(&[ace_of_hearts, five_of_clubs], &drawn).unwrap();
+    assert_eq!(redrawn.source(), "4D 3S 2D KS QS");
+    assert_eq!(
+        hand.discard_and_draw(&[ace_of_hearts], &drawn).err(),
+        Some(HandParseError::WrongCardCount(6))
+    );
+}
+
+#[test]
+fn test_from_str_rejects_duplicate_cards() {
+    let err = Hand::from_str("4S 5H 4S 8D 4H").err();
+    assert_eq!(err, Some(HandParseError::DuplicateCard(Card::new(CardValue::Four, CardSuit::Spade))))
+}
+
+#[test]
+fn test_from_str_accepts_t_for_ten() {
+    let hand = Hand::from_str("TD JH QS KD AC").unwrap();
+    assert!(hand.ties_with(&Hand::from_str("10D JH QS KD AC").unwrap()))
+}
+
+#[test]
+fn test_board_texture() {
+    let rainbow = Hand::from_str("2S 5H 7D 9C KH").unwrap();
+    let monotone = Hand::from_str("2S 4S 5S 6S 7S").unwrap();
+    let two_tone = Hand::from_str("2S 4S 5H 6H 7S").unwrap();
+    assert!(rainbow.is_rainbow() && !rainbow.is_monotone() && !rainbow.is_two_tone());
+    assert!(monotone.is_monotone() && !monotone.is_rainbow());
+    assert!(two_tone.is_two_tone());
+    assert_eq!(two_tone.suits_present().into_iter().collect::<Vec<_>>(), vec![CardSuit::Heart, CardSuit::Spade]);
+}
+
+#[test]
+fn test_has_pair_on_board() {
+    assert!(Hand::from_str("2S 2H 7D 9C KH").unwrap().has_pair_on_board());
+    assert!(Hand::from_str("2S 2H 2D 9C 9H").unwrap().has_pair_on_board());
+    assert!(!Hand::from_str("2S 2H 2D 9C KH").unwrap().has_pair_on_board());
+}
+
+#[test]
+fn test_outs_to_straight() {
+    assert_eq!(Hand::from_str("5S 6H 7D 8C KH").unwrap().number_of_outs_to_straight(), 8);
+    assert_eq!(Hand::from_str("5S 6H 8D 9C KH").unwrap().number_of_outs_to_straight(), 4);
+    assert_eq!(Hand::from_str("AS 2H 3D 4C KH").unwrap().number_of_outs_to_straight(), 4);
+    assert_eq!(Hand::from_str("JS QH KD AC 2H").unwrap().number_of_outs_to_straight(), 4);
+    assert_eq!(Hand::from_str("2S 5H 8D JC KH").unwrap().number_of_outs_to_straight(), 0);
+    assert_eq!(Hand::from_str("5S 6H 7D 8C 9H").unwrap().number_of_outs_to_straight(), 0);
+}
+
+#[test]
+fn test_outs_to_flush() {
+    assert_eq!(Hand::from_str("2H 6H 9H KH 3S").unwrap().number_of_outs_to_flush(), 9);
+    assert_eq!(Hand::from_str("2H 6H 9H KS 3S").unwrap().number_of_outs_to_flush(), 0);
+    assert_eq!(Hand::from_str("2H 6H 9H KH 3H").unwrap().number_of_outs_to_flush(), 0);
+}
+
+#[test]
+fn test_common_cards_and_blocks() {
+    let wheel = Hand::from_str("4D AH 3S 2D 5C").unwrap();
+    let aces = Hand::from_str("AH AS 2D KC 9H").unwrap();
+    let other = Hand::from_str("KH QS JD 10C 9S").unwrap();
+    assert_eq!(
+        wheel.common_cards(&aces).collect::<Vec<_>>(),
+        vec![Card::new(CardValue::Ace, CardSuit::Heart), Card::new(CardValue::Two, CardSuit::Diamond)]
+    );
+    assert!(wheel.blocks(&aces));
+    assert!(!wheel.blocks(&other));
+}
+
+#[test]
+fn test_iterate_over_hand() {
+    let hand = Hand::from_str("2H 6H 9H KS 3S").unwrap();
+    let mut hearts = 0;
+    for card in &hand {
+        if card.suit() == CardSuit::Heart { hearts += 1 }
+    }
+    assert_eq!(hearts, 3);
+    let values = hand.into_iter().map(|c| c.value()).collect::<Vec<_>>();
+    assert_eq!(values, vec![CardValue::Two, CardValue::Three, CardValue::Six, CardValue::Nine, CardValue::King]);
+}
+
+#[test]
+fn test_collect_cards_into_hand() {
+    let cards = [CardValue::Two, CardValue::Four, CardValue::Five, CardValue::Six, CardValue::Seven]
+        .map(|v| Card::new(v, CardSuit::Spade));
+    let hand: Hand = cards.into_iter().collect();
+    assert_eq!(hand.flush_suit(), Some(CardSuit::Spade));
+    let too_few = cards[..4].iter().copied().collect::<Result<Hand, _>>();
+    assert_eq!(too_few.err(), Some(HandParseError::WrongCardCount(4)));
+}
+
+#[test]
+fn test_partition_by_suit() {
+    let hand = Hand::from_str("AH KH QD JC 10H").unwrap();
+    let suits = hand.partition_by_suit();
+    assert_eq!(suits.len(), 3);
+    assert_eq!(suits[&CardSuit::Heart], vec![CardValue::Ace, CardValue::King, CardValue::Ten]);
+    assert_eq!(suits[&CardSuit::Diamond], vec![CardValue::Queen]);
+    assert_eq!(suits[&CardSuit::Club], vec![CardValue::Jack]);
+    assert_eq!(hand.cards_of_suit(CardSuit::Spade), vec![]);
+}
+
+#[test]
+fn test_cards_of_suit_plays_wheel_ace_high() {
+    let hand = Hand::from_str("4H AH 3H 2H 5H").unwrap();
+    assert_eq!(hand.cards_of_suit(CardSuit::Heart)[0], CardValue::Ace);
+}
+
+#[test]
+fn test_partition_by_value() {
+    let paired = Hand::from_str("AH AC QD QC 2S").unwrap().partition_by_value();
+    assert_eq!(paired.len(), 3);
+    assert_eq!(paired[&CardValue::Ace], vec![CardSuit::Club, CardSuit::Heart]);
+    assert_eq!(paired[&CardValue::Queen], vec![CardSuit::Club, CardSuit::Diamond]);
+    assert_eq!(paired[&CardValue::Two], vec![CardSuit::Spade]);
+    let unpaired = Hand::from_str("AH KC QD JC 2S").unwrap().partition_by_value();
+    assert!(unpaired.values().all(|suits| suits.len() == 1));
+}
+
+#[test]
+fn test_value_counts() {
+    let counts = Hand::from_str("AH AC QD QC AS").unwrap().value_counts();
+    assert_eq!(counts[&CardValue::Ace], 3);
+    assert_eq!(counts[&CardValue::Queen], 2);
+    assert_eq!(counts.get(&CardValue::Two), None);
+}
+
+#[test]
+fn test_relative_strength_vs() {
+    let hand = Hand::from_str("JD JH 2S 5C 8D").unwrap();
+    let others = ["AH KH 9C 4D 3S", "JC JS 2D 5H 8C", "QC QS 2D 5H 8C", "3C 4C 6D 5H 7C"]
+        .map(|s| Hand::from_str(s).unwrap());
+    assert_eq!(hand.relative_strength_vs(&others), 0.25);
+    assert_eq!(hand.relative_strength_vs(&others[..1]), 1.0);
+    assert_eq!(hand.relative_strength_vs(&[]), 0.0);
+}
+
+#[test]
+fn test_named_straights() {
+    let broadway = Hand::from_str("10D JH QS KD AC").unwrap();
+    assert_eq!(broadway.straight_high_card(), Some(CardValue::Ace));
+    assert!(broadway.has_broadway_straight());
+    assert!(!broadway.is_wheel_straight());
+    let wheel = Hand::from_str("4H AH 3H 2H 5H").unwrap();
+    assert_eq!(wheel.straight_high_card(), Some(CardValue::Five));
+    assert!(wheel.is_wheel_straight());
+    assert!(!wheel.has_broadway_straight());
+    let high_card = Hand::from_str("10D JH QS KD 2C").unwrap();
+    assert_eq!(high_card.straight_high_card(), None);
+    assert!(!high_card.has_broadway_straight());
+}
+
+#[test]
+fn test_value_lookups() {
+    let hand = Hand::from_str("KH KC KD 7S 2D").unwrap();
+    assert!(hand.contains_value(CardValue::King));
+    assert!(!hand.contains_value(CardValue::Ace));
+    assert_eq!(hand.count_of_value(CardValue::King), 3);
+    assert_eq!(hand.count_of_value(CardValue::Seven), 1);
+    assert_eq!(hand.count_of_value(CardValue::Ace), 0);
+    assert_eq!(hand.highest_value(), CardValue::King);
+    assert_eq!(hand.lowest_value(), CardValue::Two);
+
+    let wheel = Hand::from_str("4H AH 3H 2H 5H").unwrap();
+    assert!(wheel.contains_value(CardValue::Ace));
+    assert_eq!(wheel.highest_value(), CardValue::Ace);
+    assert_eq!(wheel.lowest_value(), CardValue::Two);
+}
+
+#[test]
+fn test_dominant_suit() {
+    let draw = Hand::from_str("2H 7H 9H KH AS").unwrap();
+    assert_eq!(draw.dominant_suit(), Some((CardSuit::Heart, 4)));
+    let tie = Hand::from_str("2H 7H 9S KS AC").unwrap();
+    assert_eq!(tie.dominant_suit(), Some((CardSuit::Spade, 2)));
+    let flush = Hand::from_str("2D 7D 9D KD AD").unwrap();
+    assert_eq!(flush.dominant_suit(), Some((CardSuit::Diamond, 5)));
+}
+
+#[test]
+fn test_from_str_checked() {
+    let mut deck = Deck::new();
+    assert!(Hand::from_str_checked("4S 5H 4C 8D 4H", &deck).is_ok());
+    let ace_of_spades = deck.deal().unwrap();
+    assert_eq!(ace_of_spades, Card::new(CardValue::Ace, CardSuit::Spade));
+    assert_eq!(
+        Hand::from_str_checked("4S 5H 4C 8D AS", &deck).err(),
+        Some(HandParseError::CardNotInDeck(ace_of_spades)),
+    );
+    assert_eq!(Hand::from_str_checked("4S 5H", &deck).err(), Some(HandParseError::WrongCardCount(2)));
+}
+
+#[test]
+fn test_owned_hands() {
+    let owned = Hand::try_from("4S 5H 4C 8D 4H".to_string()).unwrap();
+    assert_eq!(owned.source(), "4S 5H 4C 8D 4H");
+    assert!(Hand::try_from("4S 5H".to_string()).is_err());
+
+    let src = String::from("10D JH QS KD AC");
+    let borrowed = Hand::from_str(&src).unwrap();
+    let kept: Hand<'static> = borrowed.into_owned();
+    drop(src);
+    assert_eq!(kept.source(), "10D JH QS KD AC");
+    assert!(kept.beats(&owned));
+}
+
+#[test]
+fn test_ace_straights() {
+    let low = Hand::from_str("4D AH 3S 2D 5C").unwrap();
+    assert!(low.is_ace_low_straight());
+    assert!(!low.is_ace_high_straight());
+    let high = Hand::from_str("10D JH QS KD AC").unwrap();
+    assert!(high.is_ace_high_straight());
+    assert!(!high.is_ace_low_straight());
+    let low_flush = Hand::from_str("4H AH 3H 2H 5H").unwrap();
+    assert!(!low_flush.is_ace_low_straight());
+    assert!(low_flush.is_wheel_straight());
+}
+
+#[test]
+fn test_to_notation_string() {
+    use poker::NotationFormat;
+
+    let hand = Hand::from_str("10H JC QD KS AH").unwrap();
+    assert_eq!(hand.to_notation_string(NotationFormat::Compact), "AhKsQdJcTh");
+    assert_eq!(hand.to_notation_string(NotationFormat::SpaceSeparated), "Ah Ks Qd Jc Th");
+    let wheel = Hand::from_str("4D AH 3S 2D 5C").unwrap();
+    assert_eq!(wheel.to_notation_string(NotationFormat::Compact), "Ah5c4d3s2d");
+}
+
+#[test]
+fn test_gap_count() {
+    assert_eq!(Hand::from_str("3H 5C 6D 7S 8H").unwrap().gap_count(), 1);
+    assert_eq!(Hand::from_str("4D 5H 6S 7D 8C").unwrap().gap_count(), 0);
+    assert_eq!(Hand::from_str("AD 2H 3S 4D 6C").unwrap().gap_count(), 1);
+    assert_eq!(Hand::from_str("2H 3C 4D 5S KH").unwrap().gap_count(), u8::MAX);
+    assert_eq!(Hand::from_str("3H 3C 6D 7S 8H").unwrap().gap_count(), u8::MAX);
+}
+
+#[test]
+fn test_rank_predicates() {
+    let hand = |s| Hand::from_str(s).unwrap();
+    assert!(hand("4S 5H 7C 8D KH").is_high_card());
+    assert!(hand("4S 4H 7C 8D KH").is_one_pair());
+    assert!(hand("4S 4H 7C 7D KH").is_two_pair());
+    assert!(hand("4S 4H 7C 7D KH").is_double_paired());
+    assert!(hand("4S 4H 4C 7D KH").is_three_of_a_kind());
+    assert!(hand("4S 5H 6C 7D 8H").is_straight());
+    assert!(hand("4H 5H 6H 7H 9H").is_flush());
+    assert!(hand("4S 4H 4C 7D 7H").is_full_house());
+    assert!(hand("4S 4H 4C 4D 7H").is_four_of_a_kind());
+    assert!(hand("4H 5H 6H 7H 8H").is_straight_flush());
+    assert!(!hand("4H 5H 6H 7H 8H").is_straight());
+    assert!(!hand("4H 5H 6H 7H 8H").is_flush());
+}
+
+#[test]
+fn test_match_pattern() {
+    use poker::HandPattern;
+    use CardValue::*;
+
+    let pattern = |s| Hand::from_str(s).unwrap().match_pattern();
+    assert_eq!(pattern("4S 5H 7C 8D KH"), HandPattern::HighCard([King, Eight, Seven, Five, Four]));
+    assert_eq!(pattern("4S 4H 7C 8D KH"), HandPattern::OnePair { pair: Four, kickers: [King, Eight, Seven] });
+    assert_eq!(pattern("4S 4H 7C 7D KH"), HandPattern::TwoPair { high_pair: Seven, low_pair: Four, kicker: King });
+    assert_eq!(pattern("4S 4H 4C 7D KH"), HandPattern::ThreeOfAKind { trips: Four, kickers: [King, Seven] });
+    assert_eq!(pattern("4D AH 3S 2D 5C"), HandPattern::Straight { high: Five });
+    assert_eq!(
+        pattern("4H 5H 6H 7H 9H"),
+        HandPattern::Flush { values: [Nine, Seven, Six, Five, Four], suit: CardSuit::Heart },
+    );
+    assert_eq!(pattern("4S 4H 4C 7D 7H"), HandPattern::FullHouse { trips: Four, pair: Seven });
+    assert_eq!(pattern("4S 4H 4C 4D 7H"), HandPattern::FourOfAKind { quad: Four, kicker: Seven });
+    assert_eq!(pattern("10C JC QC KC AC"), HandPattern::StraightFlush { high: Ace, suit: CardSuit::Club });
+}
+
+#[test]
+fn test_decompose_for_display() {
+    let card = |s: &str| s.parse::<Card>().unwrap();
+    let trips = Hand::from_str("KH QD KS AC KD").unwrap().decompose_for_display();
+    assert_eq!(trips.rank, poker::Rank::ThreeOfAKind);
+    assert_eq!(trips.primary_cards, vec![card("KS"), card("KH"), card("KD")]);
+    assert_eq!(trips.kicker_cards, vec![card("AC"), card("QD")]);
+    assert_eq!(trips.description, "Three Kings with Ace, Queen kickers");
+
+    let full_house = Hand::from_str("9H 9D KS KC KD").unwrap().decompose_for_display();
+    assert!(full_house.kicker_cards.is_empty());
+    assert_eq!(full_house.primary_cards[0].value(), CardValue::King);
+    assert_eq!(full_house.description, "Kings full of Nines");
+
+    let wheel = Hand::from_str("4D AH 3S 2D 5C").unwrap().decompose_for_display();
+    assert_eq!(wheel.primary_cards, vec![card("5C"), card("4D"), card("3S"), card("2D"), card("AH")]);
+    assert_eq!(wheel.description, "Straight, Five high");
+
+    let descriptions = ["4S 5H 7C 8D KH", "4S 4H 7C 7D KH", "10C JC QC KC AC"]
+        .map(|s| Hand::from_str(s).unwrap().decompose_for_display().description);
+    assert_eq!(descriptions, [
+        "King high with Eight, Seven, Five, Four kickers",
+        "Sevens and Fours with King kicker",
+        "Royal flush",
+    ]);
+}
+
+#[test]
+fn test_rank_only_comparisons() {
+    use std::cmp::Ordering;
+
+    let kings_full = Hand::from_str("KH KD KS 9C 9D").unwrap();
+    let fours_full = Hand::from_str("4H 4D 4S 2C 2D").unwrap();
+    let flush = Hand::from_str("AH KH 9H 7H 2H").unwrap();
+    assert!(kings_full.rank_eq(&fours_full));
+    assert_eq!(kings_full.rank_cmp(&fours_full), Ordering::Equal);
+    assert!(kings_full.beats(&fours_full));
+    assert!(!flush.rank_eq(&fours_full));
+    assert_eq!(flush.rank_cmp(&fours_full), Ordering::Less);
+}
+
+#[test]
+fn test_fen_like_notation() {
+    let royal = Hand::from_str("10H JH QH KH AH").unwrap();
+    assert_eq!(royal.to_fen_like_notation(), "SF:AhKhQhJhTh");
+    let parsed = Hand::from_fen_like("SF:AhKhQhJhTh").unwrap();
+    assert!(parsed.ties_with(&royal));
+    let pair = Hand::from_str("4S 4H 7C 8D KH").unwrap();
+    assert!(Hand::from_fen_like(&pair.to_fen_like_notation()).unwrap().ties_with(&pair));
+
+    assert_eq!(Hand::from_fen_like("F:AhKhQhJhTh").err(), Some(HandParseError::InvalidNotation("F:AhKhQhJhTh".to_string())));
+    assert_eq!(Hand::from_fen_like("AhKhQhJhTh").err(), Some(HandParseError::InvalidNotation("AhKhQhJhTh".to_string())));
+    assert_eq!(Hand::from_fen_like("SF:AhKhQh").err(), Some(HandParseError::WrongCardCount(3)));
+}
+
+#[test]
+fn test_fen_like_notation_with_tens() {
+    let royal = Hand::from_str("10H JH QH KH AH").unwrap();
+    assert!(Hand::from_fen_like("SF:AhKhQhJh10h").unwrap().ties_with(&royal));
+    assert!(Hand::from_fen_like("SF:10hAhKhQhJh").unwrap().ties_with(&royal));
+    assert!(Hand::from_fen_like("SF:AhKhQhJh10").is_err());
+}
+
+#[test]
+fn test_block_outs() {
+    use poker::{Deck, Rank};
+
+    let hand = Hand::from_str("AS KS 2H 7D 9C").unwrap();
+    assert_eq!(hand.block_outs(Rank::Flush, &Deck::new()).len(), 5);
+
+    // only 3H 4H 5H 6H left to deal
+    let mut deck = Deck::new();
+    let left = ["3H", "4H", "5H", "6H"].map(|s| s.parse::<Card>().unwrap());
+    let dealt = deck.remaining().iter().filter(|c| !left.contains(c)).copied().collect::<Vec<_>>();
+    deck.deal_specific(&dealt).unwrap();
+    let card = |s: &str| s.parse::<Card>().unwrap();
+    assert_eq!(hand.block_outs(Rank::StraightFlush, &deck), vec![card("2H")]);
+    assert_eq!(hand.block_outs(Rank::Straight, &deck), vec![card("7D")]);
+    assert!(hand.block_outs(Rank::FourOfAKind, &deck).is_empty());
+}
+
+#[test]
+fn test_nut_flush_draw() {
+    let mut deck = Deck::new();
+    assert!(Hand::from_str("AH 6H 9H KH 3S").unwrap().is_nut_flush_draw(&deck));
+    assert!(!Hand::from_str("2H 6H 9H KH 3S").unwrap().is_nut_flush_draw(&deck));
+    assert!(!Hand::from_str("2H 6H 9H KH 3H").unwrap().is_nut_flush_draw(&deck));
+    deck.deal_specific(&["AH".parse().unwrap()]).unwrap();
+    assert!(Hand::from_str("2H 6H 9H KH 3S").unwrap().is_nut_flush_draw(&deck));
+}
+
+#[test]
+fn test_nut_straight_draw() {
+    let deck = Deck::new();
+    assert!(Hand::from_str("10S JH QD KC 2S").unwrap().is_nut_straight_draw(&deck));
+    assert!(Hand::from_str("AS KH QD 10C 2S").unwrap().is_nut_straight_draw(&deck));
+    assert!(!Hand::from_str("8S 9H 10D JC 2S").unwrap().is_nut_straight_draw(&deck));
+    assert!(!Hand::from_str("2S 5H 9D KC 3S").unwrap().is_nut_straight_draw(&deck));
+    assert!(!Hand::from_str("10S JH QD KC AS").unwrap().is_nut_straight_draw(&deck));
+}
+
+#[test]
+fn test_is_dominated_by() {
+    let ace_queen = Hand::from_str("AS QD 7C 5H 2S").unwrap();
+    let ace_king = Hand::from_str("AH KD 7S 5C 2D").unwrap();
+    let kings = Hand::from_str("KS KH 9C 4H 3S").unwrap();
+    let king_seven = Hand::from_str("KD 7H 9D 4C 3D").unwrap();
+    let queens = Hand::from_str("QS QH 8C 6H 3C").unwrap();
+    assert!(ace_queen.is_dominated_by(&ace_king));
+    assert!(ace_king.dominates(&ace_queen));
+    assert!(king_seven.is_dominated_by(&kings));
+    assert!(!ace_king.is_dominated_by(&ace_queen));
+    assert!(!king_seven.is_dominated_by(&queens));
+    assert!(!ace_queen.is_dominated_by(&Hand::from_str("2H 3D 4S 5D 6C").unwrap()));
+}
+
+#[test]
+fn test_wheel_keeps_its_ace() {
+    let wheel = Hand::from_str("4D AH 3S 2D 5C").unwrap();
+    assert!(wheel.is_straight() && wheel.is_wheel_straight());
+    assert_eq!(wheel.into_iter().next_back().map(|c| c.value()), Some(CardValue::Ace));
+    assert!(Hand::from_str("4D AH 3S 2D 5C").unwrap() < Hand::from_str("4D 6H 3S 2D 5C").unwrap());
+}
+
+#[test]
+fn test_suit_distribution_string() {
+    let distribution = |s| Hand::from_str(s).unwrap().suit_distribution_string();
+    assert_eq!(distribution("2H 6H 9H KH 3H"), "5000");
+    assert_eq!(distribution("2H 6H 9H KS 3S"), "3200");
+    assert_eq!(distribution("2H 6H 9D KS 3C"), "2111");
+    assert_eq!(distribution("2H 6D 9D KD 3C"), "3110");
+}
+
+#[test]
+fn test_connectivity_score() {
+    let connectivity = |s| Hand::from_str(s).unwrap().connectivity_score();
+    assert_eq!(connectivity("6H 7D 8S 9C 10H"), 4);
+    assert_eq!(connectivity("AH 2D 3S 4C 5H"), 4);
+    assert_eq!(connectivity("6H 6D 7S 8C 8H"), 2);
+    assert_eq!(connectivity("2H 6D 9S QC KH"), 11);
+}
+
+#[test]
+fn test_to_vec_and_as_sorted_array() {
+    let hand = Hand::from_str("4S 5H KC 8D 2H").unwrap();
+    let cards = hand.to_vec();
+    assert_eq!(cards.iter().map(|c| c.value()).collect::<Vec<_>>(), {
+        use CardValue::*;
+        [King, Eight, Five, Four, Two]
+    });
+    assert_eq!(hand.as_sorted_array().to_vec(), cards);
+}
+
+#[test]
+fn test_index_and_get() {
+    let hand = Hand::from_str("4S 5H KC 8D 2H").unwrap();
+    assert_eq!(hand[0], "2H".parse().unwrap());
+    assert_eq!(hand[4], "KC".parse().unwrap());
+    assert_eq!(hand.get(1), Some("4S".parse().unwrap()));
+    assert_eq!(hand.get(5), None);
+    assert_eq!(hand[0], hand.to_vec()[4]);
+    assert_eq!(hand.as_sorted_array()[..2], [hand[4], hand[3]]);
+}
+
+#[test]
+#[should_panic]
+fn test_index_out_of_range() {
+    let _ = Hand::from_str("4S 5H KC 8D 2H").unwrap()[5];
+}
+
+#[test]
+fn test_zip_with() {
+    use std::cmp::Ordering::*;
+    let a = Hand::from_str("4S 5H KC 8D 2H").unwrap();
+    let b = Hand::from_str("4D 6H QC 8S 2C").unwrap();
+    assert_eq!(a.zip_with(&b, |c1, c2| c1.value().cmp(&c2.value())), [Equal, Equal, Less, Equal, Greater]);
+    assert_eq!(a.zip_with(&b, |c1, c2| c1.same_suit(&c2)), [false, false, true, false, true]);
+}
+
+#[test]
+fn test_partial_cmp_detailed() {
+    use poker::{ComparisonStep::*, Rank};
+    use std::cmp::Ordering;
+    use CardValue::*;
+    let a = Hand::from_str("KS KH AD QC 3H").unwrap();
+    let b = Hand::from_str("KD KC AS JC 4H").unwrap();
+    let (order, steps) = a.partial_cmp_detailed(&b);
+    assert_eq!(order, Ordering::Greater);
+    assert_eq!(steps, [
+        RankComparison(Rank::OnePair, Rank::OnePair),
+        TiebreakerValue(King, King),
+        TiebreakerValue(Ace, Ace),
+        TiebreakerValue(Queen, Jack),
+    ]);
+    let flush = Hand::from_str("2H 6H 9H KH 3H").unwrap();
+    assert_eq!(a.partial_cmp_detailed(&flush), (Ordering::Less, vec![RankComparison(Rank::OnePair, Rank::Flush)]));
+    assert_eq!(a.partial_cmp_detailed(&a).0, Ordering::Equal);
+}
+
+#[test]
+fn test_tiebreaker_values() {
+    use CardValue::*;
+    let values = |s| Hand::from_str(s).unwrap().tiebreaker_values();
+    assert_eq!(values("KS 3H AD QC 3D"), [Three, Ace, King, Queen]);
+    assert_eq!(values("KS 3H KD QC 3D"), [King, Three, Queen]);
+    assert_eq!(values("4D AH 3S 2D 5C"), [Five]);
+    assert_eq!(values("2H 6H 9H KH 3H"), [King, Nine, Six, Three, Two]);
+}
+
+#[test]
+fn test_compressed_bytes() {
+    let hand = Hand::from_str("4S 5H KC 8D 2H").unwrap();
+    let bytes = hand.to_compressed_bytes();
+    assert_eq!(Hand::from_compressed_bytes(bytes).unwrap().to_vec(), hand.to_vec());
+    assert_eq!(Hand::from_str("2C 3C 4C 5C 6C").unwrap().to_compressed_bytes(), [0x10, 0x30, 0x81, 0x00]);
+    assert_eq!(Hand::from_compressed_bytes([0; 4]), None);
+    assert_eq!(Hand::from_compressed_bytes([0xff; 4]), None);
+}
+
+#[test]
+fn test_hands_are_equal_by_strength() {
+    let hand = Hand::from_str("2S 5H 7D 9C KH").unwrap();
+    let other_suits = Hand::from_str("2D 5C 7S 9H KD").unwrap();
+    assert_eq!(hand, other_suits);
+    assert_ne!(hand.to_vec(), other_suits.to_vec());
+    assert_ne!(hand, Hand::from_str("2S 5H 7D 9C AH").unwrap());
+}
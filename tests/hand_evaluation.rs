@@ -0,0 +1,286 @@
+use poker::{winning_hands, winning_hands_sorted};
+
+#[test]
+fn test_high_card_beats_lower_high_card() {
+    assert_eq!(winning_hands(&["2S 5H 7D 9C KH", "3S 4H 6D 8C QH"]), vec!["2S 5H 7D 9C KH"])
+}
+
+#[test]
+fn test_high_card_second_card_decides() {
+    assert_eq!(winning_hands(&["AS KH 7D 5C 3H", "AD QH 9D 5S 3C"]), vec!["AS KH 7D 5C 3H"])
+}
+
+#[test]
+fn test_high_card_third_card_decides() {
+    assert_eq!(winning_hands(&["AS KH 9D 5C 3H", "AD KC 8D 6S 4C"]), vec!["AS KH 9D 5C 3H"])
+}
+
+#[test]
+fn test_high_card_fourth_card_decides() {
+    assert_eq!(winning_hands(&["AS KH 9D 6C 3H", "AD KC 9S 5S 4C"]), vec!["AS KH 9D 6C 3H"])
+}
+
+#[test]
+fn test_high_card_last_card_decides() {
+    assert_eq!(winning_hands(&["AS KH 9D 6C 2H", "AD KC 9S 6S 3C"]), vec!["AD KC 9S 6S 3C"])
+}
+
+#[test]
+fn test_high_card_tie_ignores_suits() {
+    assert_eq!(winning_hands(&["AS KH 9D 6C 2H", "AD KC 9S 6S 2C"]), vec!["AS KH 9D 6C 2H", "AD KC 9S 6S 2C"])
+}
+
+#[test]
+fn test_one_pair_beats_high_card() {
+    assert_eq!(winning_hands(&["2S 2H 4D 5C 7H", "AS KH QD JC 9H"]), vec!["2S 2H 4D 5C 7H"])
+}
+
+#[test]
+fn test_higher_pair_wins() {
+    assert_eq!(winning_hands(&["3S 3H AD KC QH", "4S 4H 2D 5C 6H"]), vec!["4S 4H 2D 5C 6H"])
+}
+
+#[test]
+fn test_pair_tie_first_kicker() {
+    assert_eq!(winning_hands(&["8S 8H AD 5C 3H", "8D 8C KD QC JH"]), vec!["8S 8H AD 5C 3H"])
+}
+
+#[test]
+fn test_pair_tie_second_kicker() {
+    assert_eq!(winning_hands(&["8S 8H AD KC 3H", "8D 8C AS QC JH"]), vec!["8S 8H AD KC 3H"])
+}
+
+#[test]
+fn test_pair_tie_third_kicker() {
+    assert_eq!(winning_hands(&["8S 8H AD KC 3H", "8D 8C AS KS 4H"]), vec!["8D 8C AS KS 4H"])
+}
+
+#[test]
+fn test_pair_full_tie() {
+    assert_eq!(winning_hands(&["8S 8H AD KC 3H", "8D 8C AS KS 3C"]), vec!["8S 8H AD KC 3H", "8D 8C AS KS 3C"])
+}
+
+#[test]
+fn test_two_pair_beats_one_pair() {
+    assert_eq!(winning_hands(&["2S 2H 3D 3C 4H", "AS AH KD QC JH"]), vec!["2S 2H 3D 3C 4H"])
+}
+
+#[test]
+fn test_two_pair_higher_top_pair_wins() {
+    assert_eq!(winning_hands(&["KS KH 2D 2C 3H", "QS QH JD JC AH"]), vec!["KS KH 2D 2C 3H"])
+}
+
+#[test]
+fn test_two_pair_low_pair_decides() {
+    assert_eq!(winning_hands(&["KS KH 3D 3C 2H", "KD KC 2S 2C AH"]), vec!["KS KH 3D 3C 2H"])
+}
+
+#[test]
+fn test_two_pair_kicker_decides() {
+    assert_eq!(winning_hands(&["KS KH 3D 3C 2H", "KD KC 3S 3H 4H"]), vec!["KD KC 3S 3H 4H"])
+}
+
+#[test]
+fn test_two_pair_full_tie() {
+    assert_eq!(winning_hands(&["KS KH 3D 3C 4S", "KD KC 3S 3H 4H"]), vec!["KS KH 3D 3C 4S", "KD KC 3S 3H 4H"])
+}
+
+#[test]
+fn test_trips_beat_two_pair() {
+    assert_eq!(winning_hands(&["2S 2H 2D 3C 4H", "AS AH KD KC QH"]), vec!["2S 2H 2D 3C 4H"])
+}
+
+#[test]
+fn test_higher_trips_win() {
+    assert_eq!(winning_hands(&["9S 9H 9D 2C 3H", "8S 8H 8D AC KH"]), vec!["9S 9H 9D 2C 3H"])
+}
+
+#[test]
+fn test_trips_first_kicker() {
+    assert_eq!(winning_hands(&["9S 9H 9D AC 2H", "9S 9H 9D KC QH"]), vec!["9S 9H 9D AC 2H"])
+}
+
+#[test]
+fn test_trips_second_kicker() {
+    assert_eq!(winning_hands(&["9S 9H 9D AC 2H", "9S 9H 9D AD 3H"]), vec!["9S 9H 9D AD 3H"])
+}
+
+#[test]
+fn test_straight_beats_trips() {
+    assert_eq!(winning_hands(&["2S 3H 4D 5C 6H", "AS AH AD KC QH"]), vec!["2S 3H 4D 5C 6H"])
+}
+
+#[test]
+fn test_higher_straight_wins() {
+    assert_eq!(winning_hands(&["5S 6H 7D 8C 9H", "4S 5H 6D 7C 8H"]), vec!["5S 6H 7D 8C 9H"])
+}
+
+#[test]
+fn test_broadway_beats_king_high_straight() {
+    assert_eq!(winning_hands(&["10S JH QD KC AH", "9S 10H JD QC KH"]), vec!["10S JH QD KC AH"])
+}
+
+#[test]
+fn test_six_high_straight_beats_wheel() {
+    assert_eq!(winning_hands(&["2S 3H 4D 5C 6H", "AS 2H 3D 4C 5H"]), vec!["2S 3H 4D 5C 6H"])
+}
+
+#[test]
+fn test_wheel_beats_trips() {
+    assert_eq!(winning_hands(&["AS 2H 3D 4C 5H", "KS KH KD 2C 3H"]), vec!["AS 2H 3D 4C 5H"])
+}
+
+#[test]
+fn test_wheel_beats_ace_high() {
+    assert_eq!(winning_hands(&["AS 2H 3D 4C 5H", "AD KH QD JC 9H"]), vec!["AS 2H 3D 4C 5H"])
+}
+
+#[test]
+fn test_no_wraparound_straight() {
+    assert_eq!(winning_hands(&["KS AH 2D 3C 4H", "2S 2H 5D 7C 9H"]), vec!["2S 2H 5D 7C 9H"])
+}
+
+#[test]
+fn test_flush_beats_straight() {
+    assert_eq!(winning_hands(&["2H 4H 6H 8H 10H", "9S 10H JD QC KH"]), vec!["2H 4H 6H 8H 10H"])
+}
+
+#[test]
+fn test_flush_beats_broadway() {
+    assert_eq!(winning_hands(&["2H 4H 6H 8H 10H", "10S JH QD KC AH"]), vec!["2H 4H 6H 8H 10H"])
+}
+
+#[test]
+fn test_higher_flush_wins() {
+    assert_eq!(winning_hands(&["2H 4H 6H 8H AH", "3S 5S 7S 9S KS"]), vec!["2H 4H 6H 8H AH"])
+}
+
+#[test]
+fn test_flush_second_card_decides() {
+    assert_eq!(winning_hands(&["2H 4H 6H 8H AH", "3S 5S 7S 9S AS"]), vec!["3S 5S 7S 9S AS"])
+}
+
+#[test]
+fn test_flush_last_card_decides() {
+    assert_eq!(winning_hands(&["2H 4H 6H 9H AH", "3S 4S 6S 9S AS"]), vec!["3S 4S 6S 9S AS"])
+}
+
+#[test]
+fn test_flush_full_tie() {
+    assert_eq!(winning_hands(&["2H 4H 6H 9H AH", "2S 4S 6S 9S AS"]), vec!["2H 4H 6H 9H AH", "2S 4S 6S 9S AS"])
+}
+
+#[test]
+fn test_full_house_beats_flush() {
+    assert_eq!(winning_hands(&["2S 2H 2D 3C 3H", "AH KH QH JH 9H"]), vec!["2S 2H 2D 3C 3H"])
+}
+
+#[test]
+fn test_full_house_higher_trips_win() {
+    assert_eq!(winning_hands(&["3S 3H 3D 2C 2H", "2S 2D 2C AC AH"]), vec!["3S 3H 3D 2C 2H"])
+}
+
+#[test]
+fn test_full_house_pair_decides() {
+    assert_eq!(winning_hands(&["3S 3H 3D AC AH", "3S 3H 3D KC KH"]), vec!["3S 3H 3D AC AH"])
+}
+
+#[test]
+fn test_full_house_tie() {
+    assert_eq!(winning_hands(&["3S 3H 3D AC AH", "3S 3H 3C AD AS"]), vec!["3S 3H 3D AC AH", "3S 3H 3C AD AS"])
+}
+
+#[test]
+fn test_quads_beat_full_house() {
+    assert_eq!(winning_hands(&["2S 2H 2D 2C 3H", "AS AH AD KC KH"]), vec!["2S 2H 2D 2C 3H"])
+}
+
+#[test]
+fn test_higher_quads_win() {
+    assert_eq!(winning_hands(&["3S 3H 3D 3C 2H", "2S 2H 2D 2C AH"]), vec!["3S 3H 3D 3C 2H"])
+}
+
+#[test]
+fn test_quads_kicker_decides() {
+    assert_eq!(winning_hands(&["3S 3H 3D 3C 2H", "3S 3H 3D 3C 4H"]), vec!["3S 3H 3D 3C 4H"])
+}
+
+#[test]
+fn test_straight_flush_beats_quads() {
+    assert_eq!(winning_hands(&["2H 3H 4H 5H 6H", "AS AH AD AC KH"]), vec!["2H 3H 4H 5H 6H"])
+}
+
+#[test]
+fn test_higher_straight_flush_wins() {
+    assert_eq!(winning_hands(&["3H 4H 5H 6H 7H", "2S 3S 4S 5S 6S"]), vec!["3H 4H 5H 6H 7H"])
+}
+
+#[test]
+fn test_royal_flush_beats_king_high_straight_flush() {
+    assert_eq!(winning_hands(&["10H JH QH KH AH", "9S 10S JS QS KS"]), vec!["10H JH QH KH AH"])
+}
+
+#[test]
+fn test_steel_wheel_beats_quads() {
+    assert_eq!(winning_hands(&["AH 2H 3H 4H 5H", "AS AC AD KH KC"]), vec!["AH 2H 3H 4H 5H"])
+}
+
+#[test]
+fn test_steel_wheel_loses_to_six_high_straight_flush() {
+    assert_eq!(winning_hands(&["AH 2H 3H 4H 5H", "2S 3S 4S 5S 6S"]), vec!["2S 3S 4S 5S 6S"])
+}
+
+#[test]
+fn test_ace_high_flush_beats_king_high_flush() {
+    assert_eq!(winning_hands(&["AH 3H 5H 7H 9H", "KS QS JS 9S 7S"]), vec!["AH 3H 5H 7H 9H"])
+}
+
+#[test]
+fn test_pair_of_aces_beats_pair_of_kings() {
+    assert_eq!(winning_hands(&["AS AH 2D 3C 4H", "KS KH QD JC 10H"]), vec!["AS AH 2D 3C 4H"])
+}
+
+#[test]
+fn test_two_pair_aces_up_beat_kings_up() {
+    assert_eq!(winning_hands(&["AS AH 2D 2C 3H", "KS KH QD QC JH"]), vec!["AS AH 2D 2C 3H"])
+}
+
+#[test]
+fn test_low_trips_beat_high_two_pair() {
+    assert_eq!(winning_hands(&["2S 2H 2D 4C 5H", "AS AH KD KC QH"]), vec!["2S 2H 2D 4C 5H"])
+}
+
+#[test]
+fn test_low_straight_beats_high_trips() {
+    assert_eq!(winning_hands(&["2S 3H 4D 5C 6H", "AS AH AD 3C 4D"]), vec!["2S 3H 4D 5C 6H"])
+}
+
+#[test]
+fn test_low_flush_beats_high_straight() {
+    assert_eq!(winning_hands(&["2H 3H 4H 5H 7H", "10S JH QD KC AH"]), vec!["2H 3H 4H 5H 7H"])
+}
+
+#[test]
+fn test_low_full_house_beats_high_flush() {
+    assert_eq!(winning_hands(&["2S 2H 2D 3C 3H", "AH QH 10H 8H 6H"]), vec!["2S 2H 2D 3C 3H"])
+}
+
+#[test]
+fn test_low_quads_beat_high_full_house() {
+    assert_eq!(winning_hands(&["2S 2H 2D 2C 3H", "AS AH AD KC KS"]), vec!["2S 2H 2D 2C 3H"])
+}
+
+#[test]
+fn test_sorted_matches_linear() {
+    let tables: [&[&str]; 5] = [
+        &[],
+        &["4S 5S 7H 8D JC"],
+        &["2S 5H 7D 9C KH", "3S 4H 6D 8C QH"],
+        &["AS KH 9D 6C 2H", "3S 3H 6D 8C QH", "AD KC 9S 6S 2C"],
+        &["2S 4H 6D 8C 10H", "AH KH QH JH 9H", "2D 3D 4D 5D 6D", "AS KS QS JS 9S", "8S 8H 8D 2C 2H"],
+    ];
+    for hands in tables {
+        assert_eq!(winning_hands_sorted(hands), winning_hands(hands));
+    }
+    assert_eq!(winning_hands_sorted(&["AS KH 9D 6C 2H", "AD KC 9S 6S 2C"]), vec!["AS KH 9D 6C 2H", "AD KC 9S 6S 2C"]);
+}
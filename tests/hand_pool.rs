@@ -0,0 +1,28 @@
+use poker::{Hand, HandParseError, HandPool, Rank};
+
+#[test]
+fn test_pool_agrees_with_hands() {
+    let mut pool = HandPool::new();
+    for src in ["4S 5H 4C 8D 4H", "10D JH QS KD AC", "4D AH 3S 2D 5C", "2S 8H 2D 8D 3H", "3S 4S 5D 6H JH"] {
+        let hand = Hand::from_str(src).unwrap();
+        assert_eq!(pool.evaluate(src), Ok((hand.strength().to_rank(), hand.strength())), "{src}");
+    }
+    assert_eq!(pool.evaluate("4H 6H 7H 8H 5H").unwrap().0, Rank::StraightFlush);
+}
+
+#[test]
+fn test_pool_errors() {
+    let mut pool = HandPool::default();
+    assert_eq!(pool.evaluate("4S 5H 4C 8D").err(), Some(HandParseError::WrongCardCount(4)));
+    assert!(matches!(pool.evaluate("4S 5H 4C 8D 4S"), Err(HandParseError::DuplicateCard(_))));
+    assert!(matches!(pool.evaluate("4S 5H 4C 8D 1H"), Err(HandParseError::InvalidCard(_))));
+    assert!(pool.evaluate("4S 5H 4C 8D 4H").is_ok());
+}
+
+#[test]
+fn test_pool_errors_match_hands() {
+    let mut pool = HandPool::new();
+    for src in ["", "  ", "4S  5H 4C 8D 4H", "4S 5H 4C 8D 4H 9C", "4S 4S 1H 8D 4H", "4S 5H 4C 8D 4S"] {
+        assert_eq!(pool.evaluate(src).err(), Hand::from_str(src).err(), "{src:?}");
+    }
+}
@@ -0,0 +1,145 @@
+use poker::holdem::{
+    board_straight_possible, classify_two_card_holding, community_contribution, from_shorthand, is_counterfeit_safe,
+    made_hand_category, nut_rank_given_board, parse_range, ConnectorType, MadeHandCategory,
+};
+use poker::probability::prob_of_rank_or_better;
+use poker::{Card, CardSuit, CardValue, HandParseError, Rank};
+
+fn cards(values: &[CardValue]) -> Vec<Card> {
+    values.iter().map(|&v| Card::new(v, CardSuit::Heart)).collect()
+}
+
+#[test]
+fn test_connected_board_allows_a_straight() {
+    use CardValue::*;
+    assert!(board_straight_possible(&cards(&[Six, Eight, Nine])));
+    assert!(board_straight_possible(&cards(&[Ace, Three, Five, King])));
+    assert!(board_straight_possible(&cards(&[Ten, Queen, Ace, Two, Seven])));
+}
+
+#[test]
+fn test_spread_board_allows_no_straight() {
+    use CardValue::*;
+    assert!(!board_straight_possible(&cards(&[Two, Seven, Queen])));
+    assert!(!board_straight_possible(&cards(&[Two, Two, Seven, Seven, King])));
+    assert!(!board_straight_possible(&cards(&[Three, Four])));
+}
+
+#[test]
+fn test_nut_rank_given_board() {
+    use CardValue::*;
+    assert!(nut_rank_given_board(&cards(&[King, Queen, Jack, Ten, Nine])) == Rank::StraightFlush);
+    let rainbow = [Card::new(Two, CardSuit::Club), Card::new(Seven, CardSuit::Diamond), Card::new(King, CardSuit::Spade)];
+    assert!(nut_rank_given_board(&rainbow) == Rank::ThreeOfAKind);
+    let paired = [Card::new(Two, CardSuit::Club), Card::new(Two, CardSuit::Diamond), Card::new(King, CardSuit::Spade)];
+    assert!(nut_rank_given_board(&paired) == Rank::FourOfAKind);
+}
+
+#[test]
+fn test_is_counterfeit_safe() {
+    use CardValue::*;
+    let hole = [Card::new(Seven, CardSuit::Club), Card::new(Eight, CardSuit::Club)];
+    // each pair uses a hole card
+    let board = [Card::new(Seven, CardSuit::Diamond), Card::new(Eight, CardSuit::Spade), Card::new(King, CardSuit::Heart)];
+    assert!(is_counterfeit_safe(&hole, &board));
+    // the pair of Kings is all on the board
+    let board = [Card::new(Seven, CardSuit::Diamond), Card::new(King, CardSuit::Spade), Card::new(King, CardSuit::Heart)];
+    assert!(!is_counterfeit_safe(&hole, &board));
+    // no two pair to counterfeit
+    let board = [Card::new(Two, CardSuit::Diamond), Card::new(King, CardSuit::Spade), Card::new(King, CardSuit::Heart)];
+    assert!(is_counterfeit_safe(&hole, &board));
+}
+
+#[test]
+fn test_classify_two_card_holding() {
+    use CardSuit::*;
+    use CardValue::*;
+    let holding = |a: CardValue, b: CardValue, suit: CardSuit| {
+        classify_two_card_holding(&[Card::new(a, Heart), Card::new(b, suit)])
+    };
+    assert_eq!(holding(Nine, Eight, Heart), ConnectorType::SuitedConnector);
+    assert_eq!(holding(Ace, Two, Heart), ConnectorType::SuitedConnector);
+    assert_eq!(holding(Nine, Seven, Heart), ConnectorType::SuitedOneGapper);
+    assert_eq!(holding(Nine, Six, Heart), ConnectorType::SuitedTwoGapper);
+    assert_eq!(holding(Nine, Eight, Spade), ConnectorType::OffSuitConnector);
+    assert_eq!(holding(Nine, Seven, Spade), ConnectorType::OffSuitOneGapper);
+    assert_eq!(holding(Nine, Nine, Spade), ConnectorType::Pair);
+    assert_eq!(holding(King, Queen, Heart), ConnectorType::SuitedBroadway);
+    assert_eq!(holding(Ace, Ten, Spade), ConnectorType::OffSuitBroadway);
+    assert_eq!(holding(Nine, Six, Spade), ConnectorType::Other);
+    assert_eq!(holding(King, Two, Heart), ConnectorType::Other);
+}
+
+#[test]
+fn test_from_shorthand() {
+    let suited = from_shorthand("AKs").unwrap();
+    assert_eq!(suited.len(), 4);
+    assert!(suited.iter().all(|[a, b]| a.suit() == b.suit() && a.value() == CardValue::Ace && b.value() == CardValue::King));
+    let offsuit = from_shorthand("KAo").unwrap();
+    assert_eq!(offsuit.len(), 12);
+    assert!(offsuit.iter().all(|[a, b]| a.suit() != b.suit() && a.value() == CardValue::Ace));
+    assert_eq!(from_shorthand("AK").unwrap().len(), 16);
+    let aces = from_shorthand("AA").unwrap();
+    assert_eq!(aces.len(), 6);
+    assert!(aces.iter().all(|[a, b]| a.value() == b.value() && a.suit() < b.suit()));
+    assert_eq!(from_shorthand("T9s").unwrap()[0], [Card::new(CardValue::Ten, CardSuit::Club), Card::new(CardValue::Nine, CardSuit::Club)]);
+}
+
+#[test]
+fn test_from_shorthand_rejects_bad_notation() {
+    for s in ["", "A", "AAs", "AKx", "AKso", "A1s", "10Ks"] {
+        assert_eq!(from_shorthand(s), Err(HandParseError::InvalidNotation(s.to_string())), "{s}");
+    }
+}
+
+#[test]
+fn test_parse_range() {
+    assert_eq!(parse_range("TT+").unwrap().len(), 5 * 6);
+    assert_eq!(parse_range("ATs+").unwrap().len(), 4 * 4);
+    assert_eq!(parse_range("AKs,AK").unwrap().len(), 16);
+    assert_eq!(parse_range("TT+, AKs, AQs").unwrap().len(), 30 + 4 + 4);
+    assert_eq!(parse_range("TT+,AX"), Err(HandParseError::InvalidNotation("AX".to_string())));
+    assert_eq!(parse_range("AKs++"), Err(HandParseError::InvalidNotation("AKs++".to_string())));
+}
+
+fn parsed(s: &str) -> Vec<Card> {
+    s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+}
+
+#[test]
+fn test_made_hand_category() {
+    use CardValue::*;
+    let hole = |s: &str| <[Card; 2]>::try_from(parsed(s)).unwrap();
+    let board = parsed("KH 7D 2C");
+    assert_eq!(made_hand_category(&hole("7S 7C"), &board), MadeHandCategory::Set(Seven));
+    assert_eq!(made_hand_category(&hole("AS AC"), &board), MadeHandCategory::Overpair(Ace));
+    assert_eq!(made_hand_category(&hole("KS QC"), &board), MadeHandCategory::TopPair(King));
+    assert_eq!(made_hand_category(&hole("7S QC"), &board), MadeHandCategory::Pair(Seven));
+    assert_eq!(made_hand_category(&hole("KS 7C"), &board), MadeHandCategory::TopTwoPair { high: King, low: Seven });
+    assert_eq!(made_hand_category(&hole("KS 2S"), &board), MadeHandCategory::TwoPair { high: King, low: Two });
+    assert_eq!(made_hand_category(&hole("AS QC"), &board), MadeHandCategory::HighCard(Ace));
+    assert_eq!(made_hand_category(&hole("7S QC"), &parsed("7H 7D 2C")), MadeHandCategory::Trips(Seven));
+    let paired = parsed("KH KD 5C");
+    assert_eq!(made_hand_category(&hole("5S 2C"), &paired), MadeHandCategory::TwoPair { high: King, low: Five });
+}
+
+#[test]
+fn test_made_hand_category_flushes() {
+    let board = parsed("KH 9H 4H 2C");
+    let nut = <[Card; 2]>::try_from(parsed("AH 3H")).unwrap();
+    let other = <[Card; 2]>::try_from(parsed("QH JH")).unwrap();
+    assert_eq!(made_hand_category(&nut, &board), MadeHandCategory::NutFlush { suit: CardSuit::Heart });
+    assert_eq!(made_hand_category(&other, &board), MadeHandCategory::Flush { suit: CardSuit::Heart });
+}
+
+#[test]
+fn test_community_contribution() {
+    let aces = <[Card; 2]>::try_from(parsed("AS AC")).unwrap();
+    assert_eq!(community_contribution(&aces, &[]), 0.0);
+    assert_eq!(community_contribution(&aces, &parsed("10H JH QH KH AH")), 1.0);
+    assert_eq!(community_contribution(&aces, &parsed("KD 7H 2C")), 0.0);
+    assert_eq!(community_contribution(&aces, &parsed("4D 5D 6S 7C 8H")), 1.0);
+    let below = |rank: Rank| 1.0 - prob_of_rank_or_better(rank);
+    let paired = community_contribution(&aces, &parsed("2D 2H 7C"));
+    assert_eq!(paired, below(Rank::OnePair) / below(Rank::TwoPair));
+}
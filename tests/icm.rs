@@ -0,0 +1,36 @@
+use poker::icm::{icm_equity, icm_value};
+
+fn assert_close(a: f64, b: f64) {
+    assert!((a - b).abs() < 1e-9, "{a} != {b}");
+}
+
+#[test]
+fn test_winner_takes_all_is_chip_share() {
+    let equity = icm_equity(&[500, 300, 200], &[100]);
+    assert_close(equity[0], 50.0);
+    assert_close(equity[1], 30.0);
+    assert_close(equity[2], 20.0);
+}
+
+#[test]
+fn test_two_places_paid() {
+    // first: 50% / 30% / 20%, then second among the others
+    let equity = icm_equity(&[500, 300, 200], &[70, 30]);
+    let second_0 = 0.3 * 500.0 / 700.0 + 0.2 * 500.0 / 800.0;
+    assert_close(equity[0], 0.5 * 70.0 + second_0 * 30.0);
+    assert_close(equity.iter().sum(), 100.0);
+    // the short stack is worth more than its chip share
+    assert!(equity[2] > 20.0);
+}
+
+#[test]
+fn test_equal_stacks_share_equally() {
+    let equity = icm_equity(&[100; 4], &[50, 30, 20]);
+    equity.iter().for_each(|&e| assert_close(e, 25.0));
+}
+
+#[test]
+fn test_icm_value() {
+    assert_close(icm_value(500, &[70, 30], &[300, 200]), icm_equity(&[500, 300, 200], &[70, 30])[0]);
+    assert_close(icm_value(0, &[100], &[100]), 0.0);
+}
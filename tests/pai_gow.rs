@@ -0,0 +1,59 @@
+use poker::pai_gow::{eval_two_card_hand, winning_two_card_hands, TwoCardRank};
+use poker::{Card, CardSuit, CardValue};
+
+#[test]
+fn test_eval_two_card_hand() {
+    let ace = Card::new(CardValue::Ace, CardSuit::Spade);
+    let two = Card::new(CardValue::Two, CardSuit::Heart);
+    assert_eq!(eval_two_card_hand(two, ace), TwoCardRank::HighCard(CardValue::Ace, CardValue::Two));
+    assert_eq!(eval_two_card_hand(two, two.with_suit(CardSuit::Club)), TwoCardRank::Pair(CardValue::Two));
+}
+
+#[test]
+fn test_two_card_rank_order() {
+    assert!(TwoCardRank::Pair(CardValue::Two) > TwoCardRank::HighCard(CardValue::Ace, CardValue::King));
+    assert!(TwoCardRank::Pair(CardValue::Three) > TwoCardRank::Pair(CardValue::Two));
+    assert!(TwoCardRank::HighCard(CardValue::Ace, CardValue::Three) > TwoCardRank::HighCard(CardValue::Ace, CardValue::Two));
+    assert!(TwoCardRank::HighCard(CardValue::Ace, CardValue::Two) > TwoCardRank::HighCard(CardValue::King, CardValue::Queen));
+}
+
+#[test]
+fn test_winning_two_card_hands() {
+    assert_eq!(winning_two_card_hands(&["AS KD", "2H 2C", "QS QD"]), vec!["QS QD"]);
+    assert_eq!(winning_two_card_hands(&["AS KD", "KH AC", "AD QC"]), vec!["AS KD", "KH AC"]);
+    assert!(winning_two_card_hands(&[]).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_winning_two_card_hands_needs_two_cards() {
+    winning_two_card_hands(&["AS KD 2C"]);
+}
+
+#[test]
+fn test_optimal_pai_gow_split() {
+    use poker::pai_gow::optimal_pai_gow_split;
+    use poker::Hand;
+
+    // the high hand keeps its best kicker, the rest goes low
+    let (high, low) = optimal_pai_gow_split("KS KD 7C 7H AS QH 2D").unwrap();
+    assert!(high.ties_with(&Hand::from_str("KS KD 7C 7H AS").unwrap()));
+    assert_eq!(eval_two_card_hand(low[0], low[1]), TwoCardRank::HighCard(CardValue::Queen, CardValue::Two));
+
+    // the pair plays high, with the best kickers
+    let (high, low) = optimal_pai_gow_split("AS KD 9C 7H 5S 4H 4D").unwrap();
+    assert!(high.ties_with(&Hand::from_str("AS KD 9C 4H 4D").unwrap()));
+    assert_eq!(eval_two_card_hand(low[0], low[1]), TwoCardRank::HighCard(CardValue::Seven, CardValue::Five));
+}
+
+#[test]
+fn test_optimal_pai_gow_split_errors() {
+    use poker::pai_gow::optimal_pai_gow_split;
+    use poker::HandParseError;
+
+    assert_eq!(optimal_pai_gow_split("KS KD 7C 7H AS QH").err(), Some(HandParseError::WrongCardCount(6)));
+    assert_eq!(
+        optimal_pai_gow_split("KS KD 7C 7H AS QH KS").err(),
+        Some(HandParseError::DuplicateCard(Card::new(CardValue::King, CardSuit::Spade))),
+    );
+}
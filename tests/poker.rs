@@ -240,3 +240,67 @@ fn test_straight_flush_scoring() {
     // even though an ace is usually high, a 5-high straight flush is the lowest-scoring straight flush
     test(&["2H 3H 4H 5H 6H", "4D AD 3D 2D 5D"], &["2H 3H 4H 5H 6H"])
 }
+
+#[test]
+fn test_winning_hands_with_cancel() {
+    use poker::winning_hands_with_cancel;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    let input = &["4S 5H 4C 8D 4H", "10D JH QS KD AC"];
+    let cancel = AtomicBool::new(false);
+    assert_eq!(winning_hands_with_cancel(input, &cancel), Some(vec!["10D JH QS KD AC"]));
+    cancel.store(true, Ordering::Relaxed);
+    assert_eq!(winning_hands_with_cancel(input, &cancel), None);
+}
+
+#[test]
+fn test_determine_winner() {
+    use poker::{determine_winner, HandParseError, Rank, WinResult};
+
+    let winner = determine_winner(&["4S 5H 4C 8D 4H", "10D JH QS KD AC"]);
+    assert_eq!(winner, Ok(WinResult::Winner("10D JH QS KD AC", Rank::Straight)));
+    let split = determine_winner(&["3S 4S 5D 6H JH", "3H 4H 5C 6C JD", "2S 4C 7S 9H 10H"]);
+    assert_eq!(split, Ok(WinResult::SplitPot(vec!["3S 4S 5D 6H JH", "3H 4H 5C 6C JD"], Rank::HighCard)));
+    assert_eq!(determine_winner(&["3S 4S 5D 6H"]), Err(HandParseError::WrongCardCount(4)));
+    assert_eq!(determine_winner(&[]), Err(HandParseError::WrongCardCount(0)));
+}
+
+#[test]
+fn test_straights_of_different_suits_tie() {
+    test(&["5S 6H 7D 8C 9H", "5H 6S 7C 8D 9S"], &["5S 6H 7D 8C 9H", "5H 6S 7C 8D 9S"])
+}
+
+#[test]
+fn test_straight_flushes_of_different_suits_tie() {
+    test(&["4H AH 3H 2H 5H", "4C AC 3C 2C 5C"], &["4H AH 3H 2H 5H", "4C AC 3C 2C 5C"])
+}
+
+#[test]
+fn test_winning_hands_from_line() {
+    use poker::{winning_hands_from_line, HandParseError};
+    let line = "4S 5H 4C 8D 4H | 10D JH QS KD AC|3S 4S 5D 6H JH";
+    assert_eq!(winning_hands_from_line(line, '|'), Ok(vec!["10D JH QS KD AC"]));
+    let split = "3S 4S 5D 6H JH; 3H 4H 5C 6C JD";
+    assert_eq!(winning_hands_from_line(split, ';'), Ok(vec!["3S 4S 5D 6H JH", "3H 4H 5C 6C JD"]));
+    assert_eq!(winning_hands_from_line("4S 5H 4C 8D 4H | ", '|'), Err(HandParseError::WrongCardCount(0)));
+    assert_eq!(winning_hands_from_line("", '|'), Err(HandParseError::WrongCardCount(0)));
+}
+
+#[test]
+fn test_winning_hands_from_iter() {
+    use poker::winning_hands_from_iter;
+    let line = "4S 5H 4C 8D 4H,10D JH QS KD AC";
+    assert_eq!(winning_hands_from_iter(line.split(',')), vec!["10D JH QS KD AC"]);
+}
+
+#[test]
+fn test_winning_hands_from_reader() {
+    use poker::winning_hands_from_reader;
+    use std::io::{Cursor, ErrorKind};
+    let input = "3S 4S 5D 6H JH\n\n4S 5H 4C 8D 4H\n3H 4H 5C 6C JD\n4D 5D 4C 8S 4S\n";
+    let winners = winning_hands_from_reader(Cursor::new(input)).unwrap();
+    assert_eq!(winners, ["4S 5H 4C 8D 4H", "4D 5D 4C 8S 4S"]);
+    let error = winning_hands_from_reader(Cursor::new("3S 4S 5D 6H\n")).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    assert!(winning_hands_from_reader(Cursor::new("")).unwrap().is_empty());
+}
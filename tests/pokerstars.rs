@@ -0,0 +1,57 @@
+use poker::pokerstars::{DealtHand, ParseError, PokerHandResult};
+use poker::{Card, CardParseError, CardSuit, CardValue, HandParseError};
+
+const HISTORY: &str = "\
+PokerStars Hand #1: Hold'em No Limit ($0.01/$0.02 USD)
+Table 'Alpha' 6-max Seat #1 is the button
+*** HOLE CARDS ***
+Dealt to Hero [Ah Kd]
+Villain: calls $0.02
+*** SUMMARY ***
+Board [2s 3h 4d 5c Td]
+Hero: shows [Ah Kd]
+Villain: shows [Qs Qd]
+";
+
+#[test]
+fn test_from_pokerstars_str() {
+    let result = PokerHandResult::from_pokerstars_str(HISTORY).unwrap();
+    assert_eq!(result.board.len(), 5);
+    assert_eq!(result.board[4], Card::new(CardValue::Ten, CardSuit::Diamond));
+    assert_eq!(result.player_hands, vec![
+        DealtHand {
+            player: "Hero".to_string(),
+            hole_cards: [Card::new(CardValue::Ace, CardSuit::Heart), Card::new(CardValue::King, CardSuit::Diamond)],
+        },
+        DealtHand {
+            player: "Villain".to_string(),
+            hole_cards: [Card::new(CardValue::Queen, CardSuit::Spade), Card::new(CardValue::Queen, CardSuit::Diamond)],
+        },
+    ]);
+    // the wheel beats a pair of queens
+    assert_eq!(result.winners, vec!["Hero"]);
+}
+
+#[test]
+fn test_from_pokerstars_str_split_pot() {
+    let history = "Board [As Ks Qs Js Ts]\nHero: shows [2c 3c]\nVillain: shows [2d 3d]";
+    let result = PokerHandResult::from_pokerstars_str(history).unwrap();
+    assert_eq!(result.winners, vec!["Hero", "Villain"]);
+}
+
+#[test]
+fn test_from_pokerstars_str_errors() {
+    assert_eq!(PokerHandResult::from_pokerstars_str("Dealt to Hero [Ah Kd]"), Err(ParseError::MissingBoard));
+    assert_eq!(
+        PokerHandResult::from_pokerstars_str("Board [2s 3h 4d 5c 6h]\nDealt to Hero [Ah Xd]"),
+        Err(ParseError::InvalidCards(HandParseError::InvalidCard(CardParseError::InvalidValue("X".to_string())))),
+    );
+    assert_eq!(
+        PokerHandResult::from_pokerstars_str("Board [2s 3h]"),
+        Err(ParseError::InvalidCards(HandParseError::WrongCardCount(2))),
+    );
+    assert_eq!(
+        PokerHandResult::from_pokerstars_str("Board [2s 3h 4d\nDealt to Hero [Ah Kd]"),
+        Err(ParseError::UnclosedBracket("Board [2s 3h 4d".to_string())),
+    );
+}
@@ -0,0 +1,25 @@
+use poker::probability::{expected_hand_rank, prob_improving_to_rank, prob_of_rank, prob_of_rank_or_better};
+use poker::Rank;
+
+#[test]
+fn test_prob_of_rank() {
+    assert_eq!(prob_of_rank(Rank::StraightFlush), 40.0 / 2_598_960.0);
+    assert!((Rank::ALL.iter().map(|&r| prob_of_rank(r)).sum::<f64>() - 1.0).abs() < 1e-12);
+    assert_eq!(prob_of_rank_or_better(Rank::HighCard), 1.0);
+    assert_eq!(prob_of_rank_or_better(Rank::StraightFlush), prob_of_rank(Rank::StraightFlush));
+    assert!(prob_of_rank_or_better(Rank::OnePair) > prob_of_rank_or_better(Rank::TwoPair));
+}
+
+#[test]
+fn test_prob_improving_to_rank() {
+    assert_eq!(prob_improving_to_rank(Rank::Flush, Rank::Flush), None);
+    assert_eq!(prob_improving_to_rank(Rank::HighCard, Rank::OnePair), Some(prob_of_rank_or_better(Rank::OnePair)));
+    let p = prob_improving_to_rank(Rank::FourOfAKind, Rank::StraightFlush).unwrap();
+    assert_eq!(p, 40.0 / 664.0);
+}
+
+#[test]
+fn test_expected_hand_rank() {
+    let expected = expected_hand_rank();
+    assert!(expected > 0.5 && expected < 1.0, "{expected}");
+}
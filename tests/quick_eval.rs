@@ -0,0 +1,28 @@
+use poker::{Hand, HandParseError, QuickEval};
+
+#[test]
+fn test_quick_eval_orders_like_hands() {
+    let hands = ["4S 5H 4C 8D 4H", "10D JH QS KD AC", "3S 4S 5D 6H JH"];
+    let mut evals = hands.iter().map(|h| QuickEval::new(h).unwrap()).collect::<Vec<_>>();
+    evals.sort();
+    let sorted = evals.iter().map(|e| e.hand().source()).collect::<Vec<_>>();
+    assert_eq!(sorted, vec!["3S 4S 5D 6H JH", "4S 5H 4C 8D 4H", "10D JH QS KD AC"]);
+    assert_eq!(evals[2].score(), Hand::from_str("10D JH QS KD AC").unwrap().score());
+}
+
+#[test]
+fn test_quick_eval_equal_strength() {
+    assert_eq!(QuickEval::new("3S 4S 5D 6H JH").unwrap(), QuickEval::new("3H 4H 5C 6C JD").unwrap());
+    assert_eq!(QuickEval::new("3S 4S").err(), Some(HandParseError::WrongCardCount(2)));
+}
+
+#[test]
+fn test_best_hand_score() {
+    use poker::{best_hand_score, Card};
+    let cards = |s: &str| s.split(' ').map(|c| c.parse::<Card>().unwrap()).collect::<Vec<_>>();
+    let seven = "2H 9C 3D AS 4C KH 5S";
+    assert_eq!(best_hand_score(&cards(seven)), Hand::best_hand_from_str(seven).unwrap().score());
+    let five = "10D JH QS KD AC";
+    assert_eq!(best_hand_score(&cards(five)), Hand::from_str(five).unwrap().score());
+    assert!(best_hand_score(&cards("KD KH")) > best_hand_score(&cards("QD QH")));
+}
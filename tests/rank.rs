@@ -0,0 +1,122 @@
+use poker::Rank;
+
+#[test]
+fn test_frequencies_sum_to_all_hands() {
+    let total: u64 = Rank::ALL.iter().map(|r| r.frequency_in_standard_deck()).sum();
+    assert_eq!(total, 2_598_960)
+}
+
+#[test]
+fn test_rank_is_copy() {
+    let ranks = Rank::ALL;
+    let strongest = ranks[8];
+    assert!(ranks.iter().all(|&r| r <= strongest));
+    assert_eq!(strongest, Rank::StraightFlush);
+}
+
+#[test]
+fn test_rank_as_hash_key() {
+    let counts: std::collections::HashMap<Rank, usize> = Rank::ALL.iter().map(|&r| (r, 0)).collect();
+    assert_eq!(counts.len(), 9);
+    assert_eq!(format!("{:?}", Rank::FullHouse), "FullHouse");
+}
+
+#[test]
+fn test_rank_as_btree_key() {
+    use poker::{all_hands, Hand};
+    use std::collections::BTreeMap;
+
+    let mut counts = BTreeMap::<Rank, u64>::new();
+    for cards in all_hands().step_by(997) {
+        *counts.entry(Hand::from_cards(&cards).unwrap().strength().to_rank()).or_default() += 1;
+    }
+    let ranks = counts.keys().copied().collect::<Vec<_>>();
+    let mut sorted = ranks.clone();
+    sorted.sort();
+    assert_eq!(ranks, sorted);
+    assert_eq!(ranks[0], Rank::HighCard);
+}
+
+#[test]
+fn test_rank_names() {
+    assert_eq!(Rank::StraightFlush.name(), "Straight Flush");
+    assert_eq!(Rank::ThreeOfAKind.name(), "Three of a Kind");
+    assert_eq!(Rank::FourOfAKind.short_name(), "4K");
+    assert_eq!(Rank::OnePair.to_string(), Rank::OnePair.name());
+    let short = Rank::ALL.map(|r| r.short_name());
+    assert_eq!(short, ["HC", "P", "2P", "3K", "St", "F", "FH", "4K", "SF"]);
+}
+
+#[test]
+#[ignore]
+fn test_every_hand_ranks_consistently() {
+    use poker::{all_hands, best_hand_score, winning_hands, Hand};
+    use std::collections::BTreeMap;
+
+    let mut counts = BTreeMap::<Rank, u64>::new();
+    // the weakest and the strongest hand seen of each rank
+    let mut extremes = BTreeMap::<Rank, (Hand<'static>, Hand<'static>)>::new();
+    for cards in all_hands() {
+        let hand = Hand::from_cards(&cards).unwrap();
+        assert_eq!(best_hand_score(&cards), hand.score());
+        assert_eq!(Hand::from_compressed_bytes(hand.to_compressed_bytes()).map(|h| h.to_vec()), Some(hand.to_vec()));
+        let rank = hand.strength().to_rank();
+        *counts.entry(rank).or_default() += 1;
+        match extremes.get_mut(&rank) {
+            Some((weakest, strongest)) => {
+                if hand.is_weaker_than(weakest) { *weakest = hand } else if hand.beats(strongest) { *strongest = hand }
+            }
+            None => { extremes.insert(rank, (hand.clone(), hand)); }
+        }
+    }
+    for rank in Rank::ALL {
+        assert_eq!(counts[&rank], rank.frequency_in_standard_deck(), "{rank}");
+    }
+    for (lower, (_, strongest)) in &extremes {
+        for (_, (weakest, _)) in extremes.range(lower..).skip(1) {
+            assert_eq!(winning_hands(&[strongest.source(), weakest.source()]), vec![weakest.source()]);
+        }
+    }
+}
+
+#[test]
+fn test_rank_predicates() {
+    assert!(!Rank::HighCard.is_made_hand());
+    assert!(Rank::ALL[1..].iter().all(Rank::is_made_hand));
+    let suited = Rank::ALL.into_iter().filter(Rank::requires_suited_cards).collect::<Vec<_>>();
+    assert_eq!(suited, [Rank::Flush, Rank::StraightFlush]);
+    let connected = Rank::ALL.into_iter().filter(Rank::requires_connected_values).collect::<Vec<_>>();
+    assert_eq!(connected, [Rank::Straight, Rank::StraightFlush]);
+    assert!(Rank::ALL.iter().all(Rank::is_showdown_value));
+}
+
+#[test]
+fn test_all_hands_by_rank_starts_with_high_cards() {
+    use poker::all_hands_by_rank;
+    let (rank, hand) = all_hands_by_rank().next().unwrap();
+    assert_eq!(rank, Rank::HighCard);
+    assert!(hand.is_high_card());
+}
+
+#[test]
+#[ignore]
+fn test_all_hands_by_rank() {
+    use poker::{all_hands_by_rank, Hand};
+    use std::collections::BTreeMap;
+
+    let mut counts = BTreeMap::<Rank, u64>::new();
+    // the strongest hand of the lower ranks, and of the current one
+    let mut below = None::<Hand<'static>>;
+    let mut current = None::<Hand<'static>>;
+    for (rank, hand) in all_hands_by_rank() {
+        if current.as_ref().is_some_and(|c| c.strength().to_rank() != rank) {
+            below = current.take();
+        }
+        assert!(below.as_ref().is_none_or(|b| b.strength().to_rank() < rank && hand.beats(b)));
+        if current.as_ref().is_none_or(|c| hand.beats(c)) { current = Some(hand) }
+        *counts.entry(rank).or_default() += 1;
+    }
+    for rank in Rank::ALL {
+        assert_eq!(counts[&rank], rank.frequency_in_standard_deck(), "{rank}");
+    }
+}
@@ -0,0 +1,67 @@
+use poker::pokerstars::ParseError;
+use poker::replay::parse_hand_history_file;
+
+const FILE: &str = "\
+PokerStars Hand #210000000001:  Hold'em No Limit ($0.01/$0.02 USD) - 2020/03/01 12:00:00 ET
+Table 'Alpha' 6-max Seat #1 is the button
+*** HOLE CARDS ***
+Dealt to Hero [Ah Kd]
+*** SHOW DOWN ***
+Hero: shows [Ah Kd] (a pair of Aces)
+Villain: shows [Qs Qd] (a pair of Queens)
+Hero collected $0.40 from pot
+*** SUMMARY ***
+Total pot $0.40 | Rake $0
+Board [As 7c 2d 9h 3s]
+
+PokerStars Hand #210000000002:  Hold'em No Limit ($0.01/$0.02 USD) - 2020/03/01 12:01:00 ET
+*** HOLE CARDS ***
+Dealt to Hero [7h 2c]
+Hero: folds
+Villain collected $0.03 from pot
+*** SUMMARY ***
+Total pot $0.03 | Rake $0
+";
+
+#[test]
+fn test_parse_hand_history_file() {
+    let records = parse_hand_history_file(FILE).unwrap();
+    assert_eq!(records.len(), 2);
+    let first = &records[0];
+    assert_eq!(first.hand_number, 210000000001);
+    assert_eq!(first.game_type, "Hold'em No Limit");
+    assert_eq!(first.stakes, "$0.01/$0.02 USD");
+    assert_eq!(first.board.len(), 5);
+    assert_eq!(first.player_hands.len(), 2);
+    assert_eq!(first.winners, ["Hero"]);
+    assert_eq!(first.pot, "$0.40");
+    assert!(records[1].board.is_empty());
+    assert_eq!(records[1].winners, ["Villain"]);
+    assert!(parse_hand_history_file("").unwrap().is_empty());
+    assert!(matches!(parse_hand_history_file("PokerStars Hand #x: Hold'em"), Err(ParseError::InvalidHeader(_))));
+}
+
+#[test]
+fn test_evaluate_showdown() {
+    let records = parse_hand_history_file(FILE).unwrap();
+    let showdown = records[0].evaluate_showdown();
+    assert_eq!(showdown.len(), 2);
+    assert!(showdown[0].wins && !showdown[1].wins);
+    assert_eq!(showdown[0].player, "Hero");
+    assert!(showdown[0].best_hand.is_one_pair());
+    assert!(records[1].evaluate_showdown().is_empty());
+}
+
+#[test]
+fn test_showdown_results_compare_cards() {
+    use poker::replay::ShowdownResult;
+    use poker::Hand;
+
+    let result = |hand: &str| ShowdownResult {
+        player: "Hero".to_string(),
+        best_hand: Hand::try_from(hand.to_string()).unwrap(),
+        wins: true,
+    };
+    assert_eq!(result("2S 5H 7D 9C KH"), result("KH 9C 7D 5H 2S"));
+    assert_ne!(result("2S 5H 7D 9C KH"), result("2D 5C 7S 9H KD"));
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "serde")]
+
+use poker::Hand;
+
+#[test]
+fn test_serialize_hand() {
+    let hand = Hand::from_str("AS KH QD JC 10S").unwrap();
+    assert_eq!(serde_json::to_string(&hand).unwrap(), r#"{"cards":"AS KH QD JC 10S"}"#);
+}
+
+#[test]
+fn test_deserialize_hand() {
+    let hand: Hand<'static> = serde_json::from_str(r#"{ "cards": "AS KH QD JC 10S" }"#).unwrap();
+    assert_eq!(hand.source(), "AS KH QD JC 10S");
+    assert!(hand.ties_with(&Hand::from_str("10D JH QS KD AC").unwrap()));
+}
+
+#[test]
+fn test_deserialize_invalid_hand() {
+    let err = serde_json::from_str::<Hand<'static>>(r#"{ "cards": "AS KH QD" }"#).unwrap_err();
+    assert_eq!(err.to_string(), "Wrong card count: 3");
+}
@@ -0,0 +1,46 @@
+use poker::simulation::{BuildError, Simulation};
+use poker::HandParseError;
+
+#[test]
+fn test_aces_against_kings() {
+    let result = Simulation::new().hero_hand("AsAh").villain_range("KK").n_simulations(2_000).seed(7).run().unwrap();
+    assert!((0.76..0.88).contains(&result.win_rate), "{}", result.win_rate);
+    assert!((result.win_rate + result.tie_rate + result.loss_rate - 1.0).abs() < 1e-9);
+    let (low, high) = result.confidence_interval_95;
+    assert!(low < result.win_rate && result.win_rate < high);
+}
+
+#[test]
+fn test_same_seed_same_result() {
+    let simulation = Simulation::new().hero_hand("AsKs").villain_range("TT+,AKs,AQs").board("2h3h4d").n_simulations(500);
+    assert_eq!(simulation.clone().seed(42).run(), simulation.seed(42).run());
+}
+
+#[test]
+fn test_made_hand_on_the_river() {
+    // the hero holds the nut straight and nothing is left to deal
+    let result = Simulation::new().hero_hand("5s6s").villain_range("AA").board("2h3h4dKcQd").n_simulations(10).run().unwrap();
+    assert_eq!(result.win_rate, 1.0);
+}
+
+#[test]
+fn test_invalid_setups() {
+    assert_eq!(Simulation::new().villain_range("AA").run(), Err(BuildError::MissingHeroHand));
+    assert_eq!(Simulation::new().hero_hand("AsKs").run(), Err(BuildError::MissingVillainRange));
+    assert_eq!(
+        Simulation::new().hero_hand("AsKsQs").villain_range("AA").run(),
+        Err(BuildError::InvalidHeroHand(HandParseError::WrongCardCount(3))),
+    );
+    assert_eq!(
+        Simulation::new().hero_hand("AsKs").villain_range("AA").board("2h3h").run(),
+        Err(BuildError::InvalidBoard(HandParseError::WrongCardCount(2))),
+    );
+    assert_eq!(
+        Simulation::new().hero_hand("AsAh").villain_range("AA").board("AdAc2h").run(),
+        Err(BuildError::EmptyVillainRange),
+    );
+    assert_eq!(
+        Simulation::new().hero_hand("AsKs").villain_range("AA").n_simulations(0).run(),
+        Err(BuildError::NoSimulations),
+    );
+}
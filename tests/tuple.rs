@@ -0,0 +1,30 @@
+use poker::Tuple;
+
+#[test]
+fn test_poker_terms() {
+    assert_eq!(Tuple::Quad.to_poker_term(), "four of a kind");
+    assert_eq!(Tuple::Triad.to_poker_term(), "three of a kind");
+    assert_eq!(Tuple::Pair.to_poker_term(), "pair");
+    assert_eq!(Tuple::Single.to_poker_term(), "kicker");
+}
+
+#[test]
+fn test_counts() {
+    let counts = [Tuple::Quad, Tuple::Triad, Tuple::Pair, Tuple::Single].map(|t| t.to_count());
+    assert_eq!(counts, [4, 3, 2, 1])
+}
+
+#[test]
+fn test_frequency_map() {
+    use poker::{CardValue, Hand};
+    let full_house = Hand::from_str("3S 3H 3D KC KH").unwrap();
+    let freq = full_house.frequencies();
+    assert_eq!(freq.get_trips(), Some(CardValue::Three));
+    assert_eq!(freq.get_pairs().collect::<Vec<_>>(), [CardValue::King]);
+    assert!(freq.has_full_house() && !freq.has_two_pair());
+    assert_eq!(freq.get_quads(), None);
+    let two_pair = Hand::from_str("3S 3H 9D KC KH").unwrap();
+    assert!(two_pair.frequencies().has_two_pair());
+    assert_eq!(two_pair.frequencies().get_singles().collect::<Vec<_>>(), [CardValue::Nine]);
+    assert_eq!(two_pair.frequencies().get(Tuple::Pair), [CardValue::King, CardValue::Three]);
+}
@@ -0,0 +1,30 @@
+use poker::util::{cmp_kicker_chain, distinct_values, max_value, suit_count};
+use poker::{Card, CardSuit, CardValue};
+
+fn cards(s: &str) -> Vec<Card> {
+    s.split(' ').map(|c| c.parse().unwrap()).collect()
+}
+
+#[test]
+fn test_card_slice_helpers() {
+    use CardValue::*;
+    let board = cards("KH 7H 2C KD 7S");
+    assert_eq!(suit_count(CardSuit::Heart, &board), 2);
+    assert_eq!(suit_count(CardSuit::Spade, &board), 1);
+    assert_eq!(distinct_values(&board), [King, Seven, Two]);
+    assert_eq!(max_value(&board), Some(King));
+    assert_eq!(max_value(&[]), None);
+}
+
+#[test]
+fn test_cmp_kicker_chain() {
+    use std::cmp::Ordering;
+    use CardValue::*;
+    assert_eq!(cmp_kicker_chain(&[Ace, Queen, Four], &[Ace, Jack, King]), Ordering::Greater);
+    assert_eq!(cmp_kicker_chain(&[Nine, Two], &[Nine, Three]), Ordering::Less);
+    assert_eq!(cmp_kicker_chain(&[Nine, Two], &[Nine, Two]), Ordering::Equal);
+    assert_eq!(cmp_kicker_chain(&[], &[]), Ordering::Equal);
+    assert_eq!(cmp_kicker_chain(&[Ace], &[Ace, King]), Ordering::Less);
+    assert_eq!(cmp_kicker_chain(&[Ace, Two], &[Ace]), Ordering::Greater);
+    assert_eq!(cmp_kicker_chain(&[King], &[Ace, Two]), Ordering::Less);
+}
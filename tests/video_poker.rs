@@ -0,0 +1,37 @@
+use poker::video_poker::{optimal_jacks_or_better_discard, JACKS_OR_BETTER_PAY_TABLE};
+use poker::{Card, CardSuit, CardValue, Hand};
+
+#[test]
+fn test_keep_a_high_pair() {
+    let hand = Hand::from_str("AH 2C AS 7D 9H").unwrap();
+    assert_eq!(
+        optimal_jacks_or_better_discard(&hand, &JACKS_OR_BETTER_PAY_TABLE),
+        vec![
+            Card::new(CardValue::Two, CardSuit::Club),
+            Card::new(CardValue::Seven, CardSuit::Diamond),
+            Card::new(CardValue::Nine, CardSuit::Heart),
+        ]
+    )
+}
+
+#[test]
+fn test_stand_pat_on_a_flush() {
+    let hand = Hand::from_str("2S 4S 5S 9S KS").unwrap();
+    assert_eq!(optimal_jacks_or_better_discard(&hand, &JACKS_OR_BETTER_PAY_TABLE), vec![])
+}
+
+#[test]
+fn test_break_a_flush_for_a_royal_draw() {
+    // at the straight flush rate, keeping the flush pays 6 against about 2.6 for the draw
+    let hand = Hand::from_str("AH KH QH JH 3H").unwrap();
+    assert_eq!(
+        optimal_jacks_or_better_discard(&hand, &JACKS_OR_BETTER_PAY_TABLE),
+        vec![Card::new(CardValue::Three, CardSuit::Heart)]
+    )
+}
+
+#[test]
+fn test_stand_pat_on_a_royal_flush() {
+    let hand = Hand::from_str("10S JS QS KS AS").unwrap();
+    assert_eq!(optimal_jacks_or_better_discard(&hand, &JACKS_OR_BETTER_PAY_TABLE), vec![])
+}